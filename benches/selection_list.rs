@@ -0,0 +1,169 @@
+//! Measures the per-frame cost of a [`List`] of a `SelectionList` for growing
+//! numbers of options.
+//!
+//! Run with `cargo bench --bench selection_list`. The time and the number of
+//! drawn rows per frame should stay the same no matter how many options there
+//! are.
+use std::{
+    borrow::Cow,
+    hint::black_box,
+    marker::PhantomData,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use iced_aw::{
+    selection_list::List,
+    style::selection_list::{Appearance, StyleSheet},
+};
+use iced_widget::core::{
+    layout::{Limits, Node},
+    mouse::Cursor,
+    renderer,
+    text::{self, Hit, LineHeight, Shaping, Text},
+    widget::Tree,
+    Background, Font, Layout, Point, Rectangle, Size, Vector, Widget,
+};
+
+/// The number of frames drawn per measurement.
+const FRAMES: u32 = 1_000;
+
+/// A renderer counting the drawn text instead of drawing it.
+#[derive(Default)]
+struct CountingRenderer {
+    texts: usize,
+}
+
+impl renderer::Renderer for CountingRenderer {
+    type Theme = BenchTheme;
+
+    fn with_layer(&mut self, _bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        f(self);
+    }
+
+    fn with_translation(&mut self, _translation: Vector, f: impl FnOnce(&mut Self)) {
+        f(self);
+    }
+
+    fn clear(&mut self) {}
+
+    fn fill_quad(&mut self, _quad: renderer::Quad, _background: impl Into<Background>) {}
+}
+
+impl text::Renderer for CountingRenderer {
+    type Font = Font;
+
+    const ICON_FONT: Font = Font::DEFAULT;
+    const CHECKMARK_ICON: char = '0';
+    const ARROW_DOWN_ICON: char = '0';
+
+    fn default_font(&self) -> Font {
+        Font::default()
+    }
+
+    fn default_size(&self) -> f32 {
+        16.0
+    }
+
+    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+
+    fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        _line_height: LineHeight,
+        _font: Font,
+        _bounds: Size,
+        _shaping: Shaping,
+    ) -> Size {
+        Size::new(content.len() as f32 * size * 0.5, size)
+    }
+
+    fn hit_test(
+        &self,
+        _contents: &str,
+        _size: f32,
+        _line_height: LineHeight,
+        _font: Font,
+        _bounds: Size,
+        _shaping: Shaping,
+        _point: Point,
+        _nearest_only: bool,
+    ) -> Option<Hit> {
+        None
+    }
+
+    fn fill_text(&mut self, _text: Text<'_, Font>) {
+        self.texts += 1;
+    }
+}
+
+/// The theme of the [`CountingRenderer`].
+#[derive(Default)]
+struct BenchTheme;
+
+impl StyleSheet for BenchTheme {
+    type Style = ();
+
+    fn style(&self, _style: &Self::Style) -> Appearance {
+        Appearance::default()
+    }
+}
+
+/// Returns the average time and number of drawn rows of a frame of a list
+/// with the given number of options, scrolled to its middle.
+fn bench(count: usize) -> (Duration, usize) {
+    let options: Vec<String> = (0..count).map(|i| format!("Log entry {i}")).collect();
+    let list: List<'_, String, (), CountingRenderer> = List {
+        options: Rc::new(Cow::Borrowed(&options)),
+        font: Font::default(),
+        style: (),
+        on_selected: Rc::new(|_, _| ()),
+        on_selection_change: None,
+        padding: 5.0,
+        text_size: 12.0,
+        selected: None,
+        selection: None,
+        rows: None,
+        headers: Vec::new(),
+        row_builder: None,
+        on_reorder: None,
+        key: None,
+        phantomdata: PhantomData,
+    };
+
+    let tree = Tree::new(&list as &dyn Widget<(), CountingRenderer>);
+    let mut renderer = CountingRenderer::default();
+    let limits = Limits::new(Size::ZERO, Size::new(300.0, f32::INFINITY));
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        let node: Node = list.layout(&renderer, &limits);
+        let viewport = Rectangle {
+            x: 0.0,
+            y: node.size().height / 2.0,
+            width: 300.0,
+            height: 400.0,
+        };
+
+        list.draw(
+            &tree,
+            &mut renderer,
+            &BenchTheme,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            Cursor::Unavailable,
+            &viewport,
+        );
+        black_box(&renderer);
+    }
+
+    (start.elapsed() / FRAMES, renderer.texts / FRAMES as usize)
+}
+
+fn main() {
+    for count in [1_000, 10_000, 100_000, 1_000_000] {
+        let (time, rows) = bench(count);
+        println!("{count:>9} options: {time:>10.2?} per frame, {rows} rows drawn");
+    }
+}
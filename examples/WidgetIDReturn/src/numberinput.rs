@@ -1,9 +1,6 @@
 use iced::{Element, Length};
-use iced_aw::{NumberInput, NumberInputStyles};
-use num_traits::{Num, NumAssignOps};
-use std::fmt::Display;
+use iced_aw::{Number, NumberInput, NumberInputStyles};
 use std::marker::PhantomData;
-use std::str::FromStr;
 
 #[derive(Debug, Default)]
 pub struct NumInput<V, M> {
@@ -18,7 +15,7 @@ pub enum NumInputMessage<V> {
 
 impl<V> NumInputMessage<V>
 where
-    V: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    V: Number + Copy,
 {
     pub fn get_data(&self) -> V {
         let NumInputMessage::Change(data) = self;
@@ -38,7 +35,7 @@ where
 
 impl<V, M> NumInput<V, M>
 where
-    V: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    V: Number + Copy,
     M: Clone,
 {
     pub fn new(value: V) -> NumInput<V, M>
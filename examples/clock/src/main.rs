@@ -0,0 +1,104 @@
+use iced::widget::{column, container, row, text, PickList};
+use iced::{alignment, Alignment, Application, Command, Element, Length, Settings, Theme};
+use iced_aw::Clock;
+use std::fmt::{Display, Formatter};
+
+struct ClockExample {
+    theme: ThemeSelection,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ThemeSelection {
+    Dark,
+    Light,
+}
+
+impl Display for ThemeSelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeSelection::Dark => write!(f, "Dark"),
+            ThemeSelection::Light => write!(f, "Light"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    ThemeChanged(ThemeSelection),
+}
+
+const AVAILABLE_THEMES: [ThemeSelection; 2] = [ThemeSelection::Light, ThemeSelection::Dark];
+
+impl Application for ClockExample {
+    type Executor = iced::executor::Default;
+    type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
+
+    fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+        (
+            Self {
+                theme: ThemeSelection::Light,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Clock")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        match message {
+            Message::ThemeChanged(theme) => {
+                self.theme = theme;
+            }
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<Self::Message> {
+        let clocks = row![
+            column![Clock::new().width(200.0).height(200.0), text("Local time"),]
+                .align_items(Alignment::Center)
+                .spacing(10),
+            column![
+                Clock::new()
+                    .show_seconds(false)
+                    .show_ticks(false)
+                    .width(200.0)
+                    .height(200.0),
+                text("Without seconds and ticks"),
+            ]
+            .align_items(Alignment::Center)
+            .spacing(10),
+        ]
+        .spacing(40);
+
+        column![
+            container(clocks)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(alignment::Horizontal::Center)
+                .center_y(),
+            PickList::new(
+                AVAILABLE_THEMES.as_slice(),
+                Some(self.theme),
+                Message::ThemeChanged
+            ),
+        ]
+        .into()
+    }
+
+    fn theme(&self) -> Self::Theme {
+        match self.theme {
+            ThemeSelection::Dark => Theme::Dark,
+            ThemeSelection::Light => Theme::Light,
+        }
+    }
+}
+
+fn main() -> iced::Result {
+    ClockExample::run(Settings::default())
+}
@@ -0,0 +1,111 @@
+use iced::{
+    widget::{Column, Container, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+use iced_aw::{selection_list::SearchMode, ComboBox};
+
+pub fn main() -> iced::Result {
+    Example::run(Settings::default())
+}
+
+#[derive(Default)]
+struct Example {
+    languages: Vec<String>,
+    language: String,
+    editor: String,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    LanguageSelected(String),
+    EditorSelected(&'static str),
+    EditorTyped(String),
+}
+
+impl Sandbox for Example {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            languages: [
+                "C",
+                "C++",
+                "Elm",
+                "Go",
+                "Haskell",
+                "Java",
+                "Javascript",
+                "Kotlin",
+                "Python",
+                "Ruby",
+                "Rust",
+                "Swift",
+                "Typescript",
+                "Zig",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Combo box - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::LanguageSelected(language) => self.language = language,
+            Message::EditorSelected(editor) => self.editor = editor.to_owned(),
+            Message::EditorTyped(editor) => self.editor = editor,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let language = ComboBox::new(
+            &self.languages,
+            "Pick a language...",
+            &self.language,
+            |_, language| Message::LanguageSelected(language),
+        )
+        .width(Length::Fixed(250.0));
+
+        let editor = ComboBox::new(
+            &EDITORS,
+            "Type or pick an editor...",
+            &self.editor,
+            |_, editor| Message::EditorSelected(editor),
+        )
+        .on_input(Message::EditorTyped)
+        .search_mode(SearchMode::Fuzzy)
+        .width(Length::Fixed(250.0));
+
+        let content = Column::new()
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .spacing(10)
+            .push(Text::new("Which is your favorite language?"))
+            .push(language)
+            .push(Text::new(format!("{:?}", self.language)))
+            .push(Text::new("Which editor do you use?"))
+            .push(editor)
+            .push(Text::new(format!("{:?}", self.editor)));
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
+
+const EDITORS: [&str; 6] = [
+    "Emacs",
+    "Helix",
+    "Kakoune",
+    "Neovim",
+    "Visual Studio Code",
+    "Zed",
+];
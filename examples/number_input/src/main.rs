@@ -92,7 +92,18 @@ impl Application for NumberInputDemo {
                 let lb_minute = Text::new("Number Input:");
                 let txt_minute = number_input(*value, 255.0, Message::NumInpChanged)
                     .style(NumberInputStyles::Default)
-                    .step(0.5);
+                    .min(-255.0)
+                    .step(0.5)
+                    .precision(2)
+                    .suffix(" px")
+                    .validate(|value| {
+                        if value.abs() > 200.0 {
+                            Err(String::from("Values beyond 200 px are not supported"))
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    .show_messages(true);
 
                 Container::new(
                     Row::new()
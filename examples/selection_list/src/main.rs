@@ -1,8 +1,11 @@
 use iced::{
-    widget::{button, Column, Container, Space, Text},
-    Alignment, Element, Font, Length, Sandbox, Settings,
+    widget::{button, checkbox, row, text, Column, Container, Space, Text},
+    Alignment, Color, Element, Font, Length, Sandbox, Settings,
+};
+use iced_aw::{
+    selection_list::{SearchMode, SelectionList},
+    SelectionListStyles,
 };
-use iced_aw::{selection_list::SelectionList, SelectionListStyles};
 
 pub fn main() -> iced::Result {
     Example::run(Settings::default())
@@ -14,13 +17,20 @@ struct Example {
     selected_language: String,
     selected_index: usize,
     manual_select: Option<usize>,
+    selected_languages: Vec<String>,
+    favorites: Vec<String>,
+    query: String,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     LanguageSelected(usize, String),
+    LanguagesSelected(Vec<(usize, String)>),
     AddAtSelection,
     ManualSelection,
+    SearchChanged(String),
+    Reordered(usize, usize),
+    FavoriteToggled(String, bool),
 }
 
 impl Sandbox for Example {
@@ -54,6 +64,12 @@ impl Sandbox for Example {
                     self.vec.push("Rusty".into());
                 }
             }
+            Message::LanguagesSelected(selection) => {
+                self.selected_languages = selection
+                    .into_iter()
+                    .map(|(_, language)| language)
+                    .collect();
+            }
             Message::AddAtSelection => {
                 self.vec
                     .insert(self.selected_index, "Java OH NOES!".to_owned());
@@ -67,6 +83,20 @@ impl Sandbox for Example {
                     self.manual_select = Some(0);
                 }
             }
+            Message::SearchChanged(query) => {
+                self.query = query;
+            }
+            Message::FavoriteToggled(language, is_favorite) => {
+                self.favorites.retain(|favorite| *favorite != language);
+                if is_favorite {
+                    self.favorites.push(language);
+                }
+            }
+            Message::Reordered(from, to) => {
+                let language = self.vec.remove(from);
+                self.vec.insert(to, language);
+                self.manual_select = None;
+            }
         }
     }
 
@@ -80,9 +110,46 @@ impl Sandbox for Example {
             self.manual_select,
             Font::default(),
         )
-        .width(Length::Shrink)
+        .row_builder(|language, state| {
+            let dot = if state.is_selected {
+                Color::from_rgb(0.2, 0.8, 0.2)
+            } else {
+                Color::from_rgb(0.6, 0.6, 0.6)
+            };
+
+            let is_favorite = self.favorites.contains(language);
+            let toggled = language.clone();
+
+            row![
+                text("●").style(dot),
+                checkbox("", is_favorite, move |is_favorite| {
+                    Message::FavoriteToggled(toggled.clone(), is_favorite)
+                })
+                .size(12),
+                text(language),
+            ]
+            .spacing(5)
+            .into()
+        })
+        .on_reorder(Message::Reordered)
+        .width(Length::Fixed(150.0))
         .height(Length::Fixed(100.0));
 
+        let multi_selection_list = SelectionList::new(&self.vec[..], Message::LanguageSelected)
+            .multi_select(Message::LanguagesSelected)
+            .search("Search...", &self.query, Message::SearchChanged)
+            .search_mode(SearchMode::Fuzzy)
+            .width(Length::Shrink)
+            .height(Length::Fixed(130.0));
+
+        // Owned options, sorted again whenever a language is added.
+        let mut sorted = self.vec.clone();
+        sorted.sort();
+        let grouped_selection_list = SelectionList::new(sorted, Message::LanguageSelected)
+            .group_by(|language| language.chars().take(1).collect())
+            .width(Length::Shrink)
+            .height(Length::Fixed(100.0));
+
         let mut content = Column::new()
             .width(Length::Fill)
             .align_items(Alignment::Center)
@@ -91,7 +158,14 @@ impl Sandbox for Example {
             .push(Text::new("Which is your favorite language?"))
             .push(Text::new(format!("{:?}", self.selected_language)))
             .push(button("press to add at selection").on_press(Message::AddAtSelection))
-            .push(button("Manual select Index 2").on_press(Message::ManualSelection));
+            .push(button("Manual select Index 2").on_press(Message::ManualSelection))
+            .push(multi_selection_list)
+            .push(Text::new(
+                "Which languages do you know? (ctrl/shift-click to select multiple)",
+            ))
+            .push(Text::new(format!("{:?}", self.selected_languages)))
+            .push(grouped_selection_list)
+            .push(Text::new("Languages grouped by their first letter"));
 
         content = content.push(Space::with_height(Length::Fixed(800.0)));
 
@@ -1,9 +1,9 @@
 use iced::{
     alignment, font,
     widget::{container, text, Button, Column, Row, Text, TextInput},
-    Alignment, Application, Command, Element, Length, Settings, Theme,
+    Alignment, Application, Command, Element, Length, Point, Settings, Theme,
 };
-use iced_aw::{TabBar, TabLabel};
+use iced_aw::{tab_bar::Overflow, TabBar, TabLabel, TabOptions};
 
 fn main() -> iced::Result {
     TabBarExample::run(Settings::default())
@@ -13,6 +13,8 @@ fn main() -> iced::Result {
 enum Message {
     TabSelected(usize),
     TabClosed(usize),
+    TabReordered(usize, usize),
+    TabTornOff(usize, Point),
     TabLabelInputChanged(String),
     TabContentInputChanged(String),
     NewTab,
@@ -91,6 +93,20 @@ impl Application for TabBarExample {
                     };
                     println!("active tab after: {}", state.active_tab);
                 }
+                Message::TabReordered(index, new_index) => {
+                    let tab = state.tabs.remove(index);
+                    state.tabs.insert(new_index, tab);
+                    if state.active_tab == index {
+                        state.active_tab = new_index;
+                    } else if index < state.active_tab && state.active_tab <= new_index {
+                        state.active_tab -= 1;
+                    } else if new_index <= state.active_tab && state.active_tab < index {
+                        state.active_tab += 1;
+                    }
+                }
+                Message::TabTornOff(index, position) => {
+                    println!("Tab {} torn off at {:?}", index, position);
+                }
                 Message::TabLabelInputChanged(value) => state.new_tab_label = value,
                 Message::TabContentInputChanged(value) => state.new_tab_content = value,
                 Message::NewTab => {
@@ -151,14 +167,22 @@ impl Application for TabBarExample {
                             .iter()
                             .fold(
                                 TabBar::new(Message::TabSelected),
-                                |tab_bar, (tab_label, _)| {
+                                |tab_bar, (tab_label, tab_content)| {
                                     // manually create a new index for the new tab
                                     // starting from 0, when there is no tab created yet
                                     let idx = tab_bar.size();
-                                    tab_bar.push(idx, TabLabel::Text(tab_label.to_owned()))
+                                    tab_bar.push_with_options(
+                                        idx,
+                                        TabLabel::Text(tab_label.to_owned()),
+                                        TabOptions::new().tooltip(tab_content.to_owned()),
+                                    )
                                 },
                             )
                             .on_close(Message::TabClosed)
+                            .on_reorder(Message::TabReordered)
+                            .on_tear_off(Message::TabTornOff)
+                            .set_active_tab(&state.active_tab)
+                            .overflow(Overflow::Scroll)
                             .tab_width(Length::Shrink)
                             .spacing(5.0)
                             .padding(5.0)
@@ -1,10 +1,10 @@
 use iced::{
     widget::{Button, Column, Container, Row, Text},
-    Alignment, Element,
+    Alignment, Color, Element,
 };
 use iced_aw::tab_bar::TabLabel;
 
-use crate::{Icon, Message, Tab};
+use crate::{Icon, Message, Tab, ICON_FONT};
 
 #[derive(Debug, Clone)]
 pub enum CounterMessage {
@@ -36,9 +36,24 @@ impl Tab for CounterTab {
         String::from("Counter")
     }
 
-    fn tab_label(&self) -> TabLabel {
-        //TabLabel::Text(self.title())
-        TabLabel::IconText(Icon::Calc.into(), self.title())
+    fn tab_label(&self) -> TabLabel<'_, Self::Message> {
+        // Any element can be shown on a tab, here the current count next to the title.
+        TabLabel::element(
+            Row::new()
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .push(
+                    Text::new(char::from(Icon::Calc).to_string())
+                        .font(ICON_FONT)
+                        .size(20),
+                )
+                .push(Text::new(self.title()))
+                .push(
+                    Text::new(format!("({})", self.value))
+                        .size(12)
+                        .style(Color::from_rgb(0.4, 0.4, 0.4)),
+                ),
+        )
     }
 
     fn content(&self) -> Element<'_, Self::Message> {
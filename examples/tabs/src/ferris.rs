@@ -36,7 +36,7 @@ impl Tab for FerrisTab {
         String::from("Ferris")
     }
 
-    fn tab_label(&self) -> TabLabel {
+    fn tab_label(&self) -> TabLabel<'_, Self::Message> {
         TabLabel::IconText(Icon::Heart.into(), self.title())
     }
 
@@ -48,7 +48,7 @@ impl Tab for LoginTab {
         String::from("Login")
     }
 
-    fn tab_label(&self) -> TabLabel {
+    fn tab_label(&self) -> TabLabel<'_, Self::Message> {
         //TabLabel::Text(self.title())
         TabLabel::IconText(Icon::User.into(), self.title())
     }
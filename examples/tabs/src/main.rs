@@ -151,32 +151,26 @@ impl Application for TabBarExample {
                     .unwrap_or_default();
 
                 Tabs::new(Message::TabSelected)
-                    .push(
-                        TabId::Login,
-                        state.login_tab.tab_label(),
-                        state.login_tab.view(),
-                    )
-                    .push(
-                        TabId::Ferris,
-                        state.ferris_tab.tab_label(),
-                        state.ferris_tab.view(),
-                    )
-                    .push(
-                        TabId::Counter,
-                        state.counter_tab.tab_label(),
-                        state.counter_tab.view(),
-                    )
-                    .push(
-                        TabId::Settings,
-                        state.settings_tab.tab_label(),
-                        state.settings_tab.view(),
-                    )
+                    .push_lazy(TabId::Login, state.login_tab.tab_label(), move || {
+                        state.login_tab.view()
+                    })
+                    .push_lazy(TabId::Ferris, state.ferris_tab.tab_label(), move || {
+                        state.ferris_tab.view()
+                    })
+                    .push_lazy(TabId::Counter, state.counter_tab.tab_label(), move || {
+                        state.counter_tab.view()
+                    })
+                    .push_lazy(TabId::Settings, state.settings_tab.tab_label(), move || {
+                        state.settings_tab.view()
+                    })
                     .set_active_tab(&state.active_tab)
                     .tab_bar_style(theme.clone())
                     .icon_font(ICON_FONT)
                     .tab_bar_position(match position {
                         TabBarPosition::Top => iced_aw::TabBarPosition::Top,
                         TabBarPosition::Bottom => iced_aw::TabBarPosition::Bottom,
+                        TabBarPosition::Left => iced_aw::TabBarPosition::Left,
+                        TabBarPosition::Right => iced_aw::TabBarPosition::Right,
                     })
                     .into()
             }
@@ -189,7 +183,7 @@ trait Tab {
 
     fn title(&self) -> String;
 
-    fn tab_label(&self) -> TabLabel;
+    fn tab_label(&self) -> TabLabel<'_, Self::Message>;
 
     fn view(&self) -> Element<'_, Self::Message> {
         let column = Column::new()
@@ -122,11 +122,22 @@ pub fn circle_points(distance_radius: f32, center: Point, amount: u16) -> Vec<Po
     points
 }
 
+/// Calculates the end point of a clock hand with the given radius around the center.
+///
+/// The `turn` is the fraction of a full clockwise rotation starting at twelve
+/// o'clock, e.g. `0.25` points to three o'clock.
+#[must_use]
+pub fn hand_point(radius: f32, center: Point, turn: f32) -> Point {
+    let (sin, cos) = (turn * std::f32::consts::TAU).sin_cos();
+
+    Point::new(center.x + radius * sin, center.y - radius * cos)
+}
+
 #[cfg(test)]
 mod tests {
     use iced_widget::core::{Point, Vector};
 
-    use super::{circle_points, nearest_point, nearest_radius, NearestRadius};
+    use super::{circle_points, hand_point, nearest_point, nearest_radius, NearestRadius};
 
     #[test]
     fn circle_points_test() {
@@ -216,4 +227,21 @@ mod tests {
         result = nearest_point(&points, cursor_position);
         assert_eq!(index, result);
     }
+
+    #[test]
+    fn hand_point_test() {
+        let center = Point::new(10.0, 10.0);
+
+        let result = hand_point(5.0, center, 0.0);
+        assert!(result.distance(Point::new(10.0, 5.0)) < 0.001);
+
+        let result = hand_point(5.0, center, 0.25);
+        assert!(result.distance(Point::new(15.0, 10.0)) < 0.001);
+
+        let result = hand_point(5.0, center, 0.5);
+        assert!(result.distance(Point::new(10.0, 15.0)) < 0.001);
+
+        let result = hand_point(5.0, center, 0.75);
+        assert!(result.distance(Point::new(5.0, 10.0)) < 0.001);
+    }
 }
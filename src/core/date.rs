@@ -1,15 +1,48 @@
 //! Helper functions for calculating dates
 
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+    str::FromStr,
+};
 
-use chrono::Local;
-
-use chrono::{Datelike, Duration, NaiveDate};
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate};
 
 use once_cell::sync::Lazy;
 
+/// The number of seconds of a day.
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The names of the months.
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The names of the weekdays starting with Monday.
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
 /// The date value
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date {
     /// The year value of the date.
     pub year: i32,
@@ -23,8 +56,7 @@ impl Date {
     /// Creates a new date from the current timestamp.
     #[must_use]
     pub fn today() -> Self {
-        let today = Local::now().naive_local().date();
-        today.into()
+        local_today()
     }
 
     /// Creates a new date.
@@ -32,6 +64,192 @@ impl Date {
     pub const fn from_ymd(year: i32, month: u32, day: u32) -> Self {
         Self { year, month, day }
     }
+
+    /// Checks if the date exists in the calendar.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.month >= 1
+            && self.month <= 12
+            && self.day >= 1
+            && self.day <= num_days_of_month(self.year, self.month)
+    }
+
+    /// Formats the date with a `strftime` pattern of `chrono`, e.g.
+    /// `"%d.%m.%Y"` or `"%A, %B %e"`.
+    ///
+    /// An invalid date or pattern is formatted as `YYYY-MM-DD`.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn format_strftime(&self, pattern: &str) -> String {
+        use std::fmt::Write;
+
+        let mut formatted = String::new();
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+            .and_then(|date| write!(formatted, "{}", date.format(pattern)).ok())
+            .map_or_else(|| self.to_string(), |()| formatted)
+    }
+
+    /// Formats the date with a format description of `time`, e.g.
+    /// `"[day].[month].[year]"`.
+    ///
+    /// An invalid date or format description is formatted as `YYYY-MM-DD`.
+    #[cfg(feature = "time")]
+    #[must_use]
+    pub fn format_description(&self, description: &str) -> String {
+        let items = time::format_description::parse_borrowed::<2>(description).ok();
+        let date = time::Month::try_from(self.month as u8)
+            .ok()
+            .and_then(|month| {
+                time::Date::from_calendar_date(self.year, month, self.day as u8).ok()
+            });
+
+        items
+            .zip(date)
+            .and_then(|(items, date)| date.format(&items).ok())
+            .unwrap_or_else(|| self.to_string())
+    }
+
+    /// Returns the day of the week with Monday being `0`.
+    const fn weekday_from_monday(self) -> u32 {
+        // 1970-01-01 was a Thursday.
+        (self.num_days_from_epoch() + 3).rem_euclid(7) as u32
+    }
+
+    /// Returns the date moved by the given number of days.
+    const fn add_days(self, days: i64) -> Self {
+        Self::from_num_days_from_epoch(self.num_days_from_epoch() + days)
+    }
+
+    /// Returns the number of days since 1970-01-01 in the proleptic
+    /// Gregorian calendar.
+    const fn num_days_from_epoch(self) -> i64 {
+        let month = self.month as i64;
+        let year = self.year as i64 - if month <= 2 { 1 } else { 0 };
+
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates the date from the number of days since 1970-01-01 in the
+    /// proleptic Gregorian calendar.
+    const fn from_num_days_from_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Self::from_ymd(year as i32, month as u32, day as u32)
+    }
+}
+
+/// Gets the number of whole days of the duration.
+const fn num_whole_days(duration: std::time::Duration) -> i64 {
+    (duration.as_secs() / SECONDS_PER_DAY) as i64
+}
+
+/// Gets the current local date from `chrono`.
+#[cfg(feature = "chrono")]
+fn local_today() -> Date {
+    chrono::Local::now().date_naive().into()
+}
+
+/// Gets the current local date from `time`, falling back to UTC if the
+/// local offset is unknown.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+fn local_today() -> Date {
+    time::OffsetDateTime::now_local()
+        .unwrap_or_else(|_| time::OffsetDateTime::now_utc())
+        .date()
+        .into()
+}
+
+impl Add<std::time::Duration> for Date {
+    type Output = Self;
+
+    /// Adds the whole days of the duration to the date.
+    fn add(self, rhs: std::time::Duration) -> Self::Output {
+        self.add_days(num_whole_days(rhs))
+    }
+}
+
+impl Sub<std::time::Duration> for Date {
+    type Output = Self;
+
+    /// Subtracts the whole days of the duration from the date.
+    fn sub(self, rhs: std::time::Duration) -> Self::Output {
+        self.add_days(-num_whole_days(rhs))
+    }
+}
+
+/// An error which can be returned when parsing a [`Date`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDateError {
+    /// The string does not match the expected format.
+    InvalidFormat,
+    /// A component of the value is out of its range.
+    OutOfRange,
+}
+
+impl Display for ParseDateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::InvalidFormat => "invalid format",
+                Self::OutOfRange => "value out of range",
+            }
+        )
+    }
+}
+
+impl std::error::Error for ParseDateError {}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    /// Parses a date in the format `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(3, '-');
+
+        let mut next = || {
+            parts
+                .next()
+                .filter(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+                .ok_or(ParseDateError::InvalidFormat)
+        };
+
+        let (year, month, day) = (next()?, next()?, next()?);
+
+        let date = Self::from_ymd(
+            year.parse().ok().ok_or(ParseDateError::OutOfRange)?,
+            month.parse().ok().ok_or(ParseDateError::OutOfRange)?,
+            day.parse().ok().ok_or(ParseDateError::OutOfRange)?,
+        );
+
+        if date.is_valid() {
+            Ok(date)
+        } else {
+            Err(ParseDateError::OutOfRange)
+        }
+    }
 }
 
 impl Display for Date {
@@ -40,6 +258,7 @@ impl Display for Date {
     }
 }
 
+#[cfg(feature = "chrono")]
 impl From<Date> for NaiveDate {
     fn from(date: Date) -> Self {
         Self::from_ymd_opt(date.year, date.month, date.day)
@@ -47,94 +266,107 @@ impl From<Date> for NaiveDate {
     }
 }
 
+#[cfg(feature = "chrono")]
 impl From<NaiveDate> for Date {
     fn from(date: NaiveDate) -> Self {
         Self::from_ymd(date.year(), date.month(), date.day())
     }
 }
 
-/// # Panics
+#[cfg(feature = "time")]
+impl From<Date> for time::Date {
+    fn from(date: Date) -> Self {
+        time::Month::try_from(date.month as u8)
+            .and_then(|month| Self::from_calendar_date(date.year, month, date.day as u8))
+            .expect("Year, Month or Day doesnt Exist")
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for Date {
+    fn from(date: time::Date) -> Self {
+        Self::from_ymd(
+            date.year(),
+            u32::from(u8::from(date.month())),
+            u32::from(date.day()),
+        )
+    }
+}
+
 /// Creates a date with the previous month based on the given date.
-/// panics if year, month or day doesnt exist.
 #[must_use]
-pub fn pred_month(date: NaiveDate) -> NaiveDate {
-    let (year, month) = if date.month() == 1 {
-        (date.year() - 1, 12)
+pub fn pred_month(date: Date) -> Date {
+    let (year, month) = if date.month == 1 {
+        (date.year - 1, 12)
     } else {
-        (date.year(), date.month() - 1)
+        (date.year, date.month - 1)
     };
 
-    let day = date.day().min(num_days_of_month(year, month));
+    let day = date.day.min(num_days_of_month(year, month));
 
-    NaiveDate::from_ymd_opt(year, month, day).expect("Year, Month or Day doesnt Exist")
+    Date::from_ymd(year, month, day)
 }
 
-/// # Panics
 /// Creates a date with the next month based on given date.
-/// panics if year, month or day doesnt exist.
 #[must_use]
-pub fn succ_month(date: NaiveDate) -> NaiveDate {
-    let (year, month) = if date.month() == 12 {
-        (date.year() + 1, 1)
+pub fn succ_month(date: Date) -> Date {
+    let (year, month) = if date.month == 12 {
+        (date.year + 1, 1)
     } else {
-        (date.year(), date.month() + 1)
+        (date.year, date.month + 1)
     };
 
-    let day = date.day().min(num_days_of_month(year, month));
+    let day = date.day.min(num_days_of_month(year, month));
 
-    NaiveDate::from_ymd_opt(year, month, day).expect("Year, Month or Day doesnt Exist")
+    Date::from_ymd(year, month, day)
 }
 
-/// # Panics
 /// Creates a date with the previous year based on the given date.
-// panics if year, month or day doesnt exist.
 
 #[must_use]
-pub fn pred_year(date: NaiveDate) -> NaiveDate {
-    let year = date.year() - 1;
-    let day = date.day().min(num_days_of_month(year, date.month()));
+pub fn pred_year(date: Date) -> Date {
+    let year = date.year - 1;
+    let day = date.day.min(num_days_of_month(year, date.month));
 
-    NaiveDate::from_ymd_opt(year, date.month(), day).expect("Year, Month or Day doesnt Exist")
+    Date::from_ymd(year, date.month, day)
 }
 
-/// # Panics
 /// Creates a date with the next year based on the given date.
-// panics if year, month or day doesnt exist.
 
 #[must_use]
-pub fn succ_year(date: NaiveDate) -> NaiveDate {
-    let year = date.year() + 1;
-    let day = date.day().min(num_days_of_month(year, date.month()));
+pub fn succ_year(date: Date) -> Date {
+    let year = date.year + 1;
+    let day = date.day.min(num_days_of_month(year, date.month));
 
-    NaiveDate::from_ymd_opt(year, date.month(), day).expect("Year, Month or Day doesnt Exist")
+    Date::from_ymd(year, date.month, day)
 }
 
 /// Calculates a date with the previous week based on the given date.
 
 #[must_use]
-pub fn pred_week(date: NaiveDate) -> NaiveDate {
-    date - Duration::days(7)
+pub fn pred_week(date: Date) -> Date {
+    date.add_days(-7)
 }
 
 /// Calculates a date with the next week based on the given date.
 
 #[must_use]
-pub fn succ_week(date: NaiveDate) -> NaiveDate {
-    date + Duration::days(7)
+pub fn succ_week(date: Date) -> Date {
+    date.add_days(7)
 }
 
 /// Calculates a date with the previous day based on the given date.
 
 #[must_use]
-pub fn pred_day(date: NaiveDate) -> NaiveDate {
-    date - Duration::days(1)
+pub fn pred_day(date: Date) -> Date {
+    date.add_days(-1)
 }
 
 /// Calculates a date with the next day based on the given date.
 
 #[must_use]
-pub fn succ_day(date: NaiveDate) -> NaiveDate {
-    date + Duration::days(1)
+pub fn succ_day(date: Date) -> Date {
+    date.add_days(1)
 }
 
 /// Specifies if the calculated day lays in the previous, same or next month of
@@ -152,24 +384,21 @@ pub enum IsInMonth {
     Next,
 }
 
-/// # Panics
 /// Calculates the day number at the given position in the calendar table based
 /// on the given year and month.
-/// panics if year, month or day does not exist.
 #[must_use]
 pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsInMonth) {
     let (x, y) = (x as isize, y as isize);
-    let first_day =
-        NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
-    let day_of_week = first_day.weekday().num_days_from_monday() as isize;
+    let first_day = Date::from_ymd(year, month, 1);
+    let day_of_week = first_day.weekday_from_monday() as isize;
     let day_of_week = if day_of_week == 0 { 7 } else { day_of_week };
 
     let day = (x + 7 * y) + 1 - day_of_week;
 
     if day < 1 {
-        let last_month = first_day.pred_opt().unwrap_or_default();
+        let last_month = pred_month(first_day);
         (
-            (num_days_of_month(last_month.year(), last_month.month()) as isize + day) as usize,
+            (num_days_of_month(last_month.year, last_month.month) as isize + day) as usize,
             IsInMonth::Previous,
         )
     } else if day > num_days_of_month(year, month) as isize {
@@ -211,161 +440,117 @@ const fn num_days_of_month(year: i32, month: u32) -> u32 {
 /// Gets the string representation of the year of the given date.
 
 #[must_use]
-pub fn year_as_string(date: NaiveDate) -> String {
-    date.format("%Y").to_string()
+pub fn year_as_string(date: Date) -> String {
+    format!("{:04}", date.year)
 }
 
 /// Gets the string representation of the month of the given date.
 
 #[must_use]
-pub fn month_as_string(date: NaiveDate) -> String {
-    date.format("%B").to_string()
+pub fn month_as_string(date: Date) -> String {
+    MONTH_NAMES
+        .get(date.month.wrapping_sub(1) as usize)
+        .map_or_else(String::new, |name| (*name).to_owned())
 }
 
 /// Gets the length of the longest month name.
 pub static MAX_MONTH_STR_LEN: Lazy<usize> = Lazy::new(|| {
-    let months = [
-        NaiveDate::from_ymd_opt(0, 1, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 2, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 3, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 4, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 5, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 6, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 7, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 8, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 9, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 10, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 11, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 12, 1).expect("Year, Month or Day doesnt Exist"),
-    ];
-
-    let max = months
+    MONTH_NAMES
         .iter()
-        .map(|m| month_as_string(*m))
-        .map(|s| s.len())
+        .map(|name| name.len())
         .max()
-        .expect("There should be a maximum element");
-
-    max
+        .expect("There should be a maximum element")
 });
 
 /// Gets the labels of the weekdays containing the first two characters of
 /// the weekdays.
 pub static WEEKDAY_LABELS: Lazy<Vec<String>> = Lazy::new(|| {
-    let days = [
-        // Monday
-        NaiveDate::from_ymd_opt(2020, 6, 1).expect("Year, Month or Day doesnt Exist"),
-        // Tuesday
-        NaiveDate::from_ymd_opt(2020, 6, 2).expect("Year, Month or Day doesnt Exist"),
-        // Wednesday
-        NaiveDate::from_ymd_opt(2020, 6, 3).expect("Year, Month or Day doesnt Exist"),
-        // Thursday
-        NaiveDate::from_ymd_opt(2020, 6, 4).expect("Year, Month or Day doesnt Exist"),
-        // Friday
-        NaiveDate::from_ymd_opt(2020, 6, 5).expect("Year, Month or Day doesnt Exist"),
-        // Saturday
-        NaiveDate::from_ymd_opt(2020, 6, 6).expect("Year, Month or Day doesnt Exist"),
-        // Sunday
-        NaiveDate::from_ymd_opt(2020, 6, 7).expect("Year, Month or Day doesnt Exist"),
-    ];
-
-    days.iter()
-        .map(|d| d.format("%a").to_string())
-        .map(|s| s[0..2].to_owned())
+    WEEKDAY_NAMES
+        .iter()
+        .map(|name| name[0..2].to_owned())
         .collect()
 });
 
 #[cfg(test)]
 
 mod tests {
-    use chrono::NaiveDate;
+    use std::time::Duration;
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        is_leap_year, month_as_string, num_days_of_month, position_to_day, pred_day, pred_month,
+        pred_week, pred_year, succ_day, succ_month, succ_week, succ_year, year_as_string, Date,
+        IsInMonth, ParseDateError, WEEKDAY_LABELS,
     };
 
     #[test]
     fn pred_month_test() {
-        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 5, 6);
         let result = pred_month(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2020, 4, 6).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2020, 4, 6);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2020, 1, 24).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 1, 24);
         let result = pred_month(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2019, 12, 24).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2019, 12, 24);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2020, 3, 31).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 3, 31);
         let result = pred_month(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2020, 2, 29).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2020, 2, 29);
         assert_eq!(result, expected);
     }
 
     #[test]
     fn succ_month_test() {
-        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 5, 6);
         let result = succ_month(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2020, 6, 6).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2020, 6, 6);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2019, 12, 24).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2019, 12, 24);
         let result = succ_month(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2020, 1, 24).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2020, 1, 24);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2020, 1, 31).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 1, 31);
         let result = succ_month(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2020, 2, 29).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2020, 2, 29);
         assert_eq!(result, expected);
     }
 
     #[test]
     fn pred_year_test() {
-        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 5, 6);
         let result = pred_year(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2019, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2019, 5, 6);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2020, 2, 29).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 2, 29);
         let result = pred_year(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2019, 2, 28).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2019, 2, 28);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2021, 2, 28).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2021, 2, 28);
         let result = pred_year(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2020, 2, 28).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2020, 2, 28);
         assert_eq!(result, expected);
     }
 
     #[test]
     fn succ_year_test() {
-        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 5, 6);
         let result = succ_year(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2021, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2021, 5, 6);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2020, 2, 29).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2020, 2, 29);
         let result = succ_year(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2021, 2, 28).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2021, 2, 28);
         assert_eq!(result, expected);
 
-        let date = NaiveDate::from_ymd_opt(2019, 2, 28).expect("Year, Month or Day doesnt Exist");
+        let date = Date::from_ymd(2019, 2, 28);
         let result = succ_year(date);
-        let expected =
-            NaiveDate::from_ymd_opt(2020, 2, 28).expect("Year, Month or Day doesnt Exist");
+        let expected = Date::from_ymd(2020, 2, 28);
         assert_eq!(result, expected);
     }
 
@@ -446,4 +631,123 @@ mod tests {
         assert_eq!(num_days_of_month(2020, 11), 30);
         assert_eq!(num_days_of_month(2020, 12), 31);
     }
+
+    #[test]
+    fn date_from_str_test() {
+        assert_eq!(
+            "2020-02-29".parse::<Date>(),
+            Ok(Date::from_ymd(2020, 2, 29))
+        );
+        assert_eq!(
+            " 2021-12-01 ".parse::<Date>(),
+            Ok(Date::from_ymd(2021, 12, 1))
+        );
+
+        assert_eq!(
+            "2021-02-29".parse::<Date>(),
+            Err(ParseDateError::OutOfRange)
+        );
+        assert_eq!(
+            "2021-13-01".parse::<Date>(),
+            Err(ParseDateError::OutOfRange)
+        );
+        assert_eq!(
+            "2021-12".parse::<Date>(),
+            Err(ParseDateError::InvalidFormat)
+        );
+        assert_eq!(
+            "2021/12/01".parse::<Date>(),
+            Err(ParseDateError::InvalidFormat)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn date_format_strftime_test() {
+        let date = Date::from_ymd(2020, 6, 1);
+        assert_eq!(date.format_strftime("%d.%m.%Y"), "01.06.2020");
+        assert_eq!(date.format_strftime("%A"), "Monday");
+        assert_eq!(date.format_strftime("%Q"), "2020-06-01");
+        assert_eq!(
+            Date::from_ymd(2021, 2, 29).format_strftime("%d.%m.%Y"),
+            "2021-02-29"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn date_format_description_test() {
+        let date = Date::from_ymd(2020, 6, 1);
+        assert_eq!(
+            date.format_description("[day].[month].[year]"),
+            "01.06.2020"
+        );
+        assert_eq!(date.format_description("[weekday]"), "Monday");
+        assert_eq!(date.format_description("[day"), "2020-06-01");
+        assert_eq!(
+            Date::from_ymd(2021, 2, 29).format_description("[day].[month].[year]"),
+            "2021-02-29"
+        );
+    }
+
+    #[test]
+    fn date_arithmetic_test() {
+        const SECONDS_PER_DAY: u64 = 86_400;
+
+        let date = Date::from_ymd(2020, 2, 28);
+        assert_eq!(
+            date + Duration::from_secs(2 * SECONDS_PER_DAY),
+            Date::from_ymd(2020, 3, 1)
+        );
+        assert_eq!(
+            date - Duration::from_secs(59 * SECONDS_PER_DAY + 3600),
+            Date::from_ymd(2019, 12, 31)
+        );
+        assert_eq!(
+            date + Duration::from_secs(366 * SECONDS_PER_DAY),
+            Date::from_ymd(2021, 2, 28)
+        );
+        assert_eq!(
+            Date::from_ymd(1970, 1, 1) - Duration::from_secs(SECONDS_PER_DAY),
+            Date::from_ymd(1969, 12, 31)
+        );
+    }
+
+    #[test]
+    fn date_calendar_test() {
+        assert_eq!(
+            pred_week(Date::from_ymd(2020, 3, 3)),
+            Date::from_ymd(2020, 2, 25)
+        );
+        assert_eq!(
+            succ_week(Date::from_ymd(2020, 12, 28)),
+            Date::from_ymd(2021, 1, 4)
+        );
+        assert_eq!(
+            pred_day(Date::from_ymd(2000, 3, 1)),
+            Date::from_ymd(2000, 2, 29)
+        );
+        assert_eq!(
+            succ_day(Date::from_ymd(1900, 2, 28)),
+            Date::from_ymd(1900, 3, 1)
+        );
+
+        assert_eq!(year_as_string(Date::from_ymd(812, 1, 1)), "0812");
+        assert_eq!(month_as_string(Date::from_ymd(2020, 9, 1)), "September");
+        assert_eq!(WEEKDAY_LABELS.join(" "), "Mo Tu We Th Fr Sa Su");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn date_to_time_crate_test() {
+        let date = Date::from_ymd(2020, 2, 29);
+
+        let converted: time::Date = date.into();
+        assert_eq!(
+            converted,
+            time::Date::from_calendar_date(2020, time::Month::February, 29)
+                .expect("Year, Month or Day doesnt Exist")
+        );
+        assert_eq!(Date::from(converted), date);
+    }
 }
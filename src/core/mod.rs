@@ -1,18 +1,32 @@
 //! A module fitting `iced_core`.
 
+#[cfg(all(
+    any(feature = "date_picker", feature = "time_picker", feature = "clock"),
+    not(any(feature = "chrono", feature = "time"))
+))]
+compile_error!(
+    "the `date_picker`, `time_picker` and `clock` features need the `chrono` or the `time` feature"
+);
+
 #[cfg(feature = "date_picker")]
 //#[cfg(all(feature = "date_picker", not(target_arch = "wasm32")))]
 pub mod date;
 
-#[cfg(feature = "time_picker")]
+#[cfg(any(feature = "time_picker", feature = "clock"))]
 pub mod clock;
 
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+#[cfg(feature = "number_input")]
+pub mod number;
+
 pub mod overlay;
 
 pub mod renderer;
 
-#[cfg(feature = "time_picker")]
+#[cfg(feature = "selection_list")]
+pub mod search;
+
+#[cfg(any(feature = "time_picker", feature = "clock"))]
 pub mod time;
@@ -0,0 +1,683 @@
+//! Helper functions for formatting and parsing numbers of a number input.
+
+/// The radix an integer is shown and typed in.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Radix {
+    /// Base 10, e.g. `255`.
+    #[default]
+    Decimal,
+    /// Base 16, e.g. `0xFF`.
+    Hexadecimal,
+    /// Base 8, e.g. `0o377`.
+    Octal,
+    /// Base 2, e.g. `0b11111111`.
+    Binary,
+}
+
+impl Radix {
+    /// Returns the base of the [`Radix`].
+    #[must_use]
+    pub const fn base(self) -> u32 {
+        match self {
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+            Self::Octal => 8,
+            Self::Binary => 2,
+        }
+    }
+
+    /// Returns the prefix written in front of numbers in the [`Radix`].
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Decimal => "",
+            Self::Hexadecimal => "0x",
+            Self::Octal => "0o",
+            Self::Binary => "0b",
+        }
+    }
+}
+
+/// A number that can be edited by a
+/// [`NumberInput`](crate::native::number_input::NumberInput).
+///
+/// It is implemented for all primitive integer and floating point types, but
+/// can be implemented for custom types like fixed-point decimals as well.
+pub trait Number: Clone + PartialOrd {
+    /// Returns zero, the default minimum.
+    fn zero() -> Self;
+
+    /// Returns one, the default step.
+    fn one() -> Self;
+
+    /// Adds the other number, saturating at the numeric bounds.
+    #[must_use]
+    fn add(&self, other: &Self) -> Self;
+
+    /// Subtracts the other number, saturating at the numeric bounds.
+    #[must_use]
+    fn sub(&self, other: &Self) -> Self;
+
+    /// Multiplies the number by ten, used for coarse steps.
+    #[must_use]
+    fn times_ten(&self) -> Self;
+
+    /// Divides the number by ten, used for fine steps.
+    #[must_use]
+    fn tenth(&self) -> Self;
+
+    /// Checks if the type is able to hold fractional numbers.
+    fn is_fractional() -> bool;
+
+    /// Parses the number from a text using `.` as decimal separator.
+    fn parse(text: &str) -> Option<Self>;
+
+    /// Formats the number using `.` as decimal separator and the given number
+    /// of decimal places, if any.
+    ///
+    /// Without a precision, floats are formatted with the shortest text that
+    /// keeps their significant digits, e.g. `0.1 + 0.2` as `0.3`.
+    fn format(&self, precision: Option<usize>) -> String;
+
+    /// Formats the number using `.` as decimal separator without losing
+    /// precision, so that [`parse`](Self::parse) returns the same number.
+    ///
+    /// Defaults to [`format`](Self::format) without a precision.
+    fn format_exact(&self) -> String {
+        self.format(None)
+    }
+
+    /// Converts the number into a float for evaluating arithmetic expressions.
+    fn to_f64(&self) -> Option<f64> {
+        self.format_exact().parse().ok()
+    }
+
+    /// Converts the result of an arithmetic expression into a number.
+    #[must_use]
+    fn from_f64(value: f64) -> Option<Self> {
+        Self::parse(&value.to_string())
+    }
+
+    /// Formats the number in the given [`Radix`] including its prefix, e.g.
+    /// `-0xFF`. Returns `None` if the radix is not supported.
+    fn format_radix(&self, _radix: Radix) -> Option<String> {
+        None
+    }
+
+    /// Parses the number in the given [`Radix`], see [`normalize_radix`].
+    /// Returns `None` if the text is invalid or the radix is not supported.
+    #[must_use]
+    fn parse_radix(_text: &str, _radix: Radix) -> Option<Self> {
+        None
+    }
+}
+
+/// Implements [`Number`] for primitive integer types. The closure splits a
+/// value into its sign and its magnitude.
+macro_rules! impl_integer {
+    ($($t:ty => $split:expr),* $(,)?) => {
+        $(
+            impl Number for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn add(&self, other: &Self) -> Self {
+                    self.saturating_add(*other)
+                }
+
+                fn sub(&self, other: &Self) -> Self {
+                    self.saturating_sub(*other)
+                }
+
+                fn times_ten(&self) -> Self {
+                    self.saturating_mul(10)
+                }
+
+                fn tenth(&self) -> Self {
+                    self / 10
+                }
+
+                fn is_fractional() -> bool {
+                    false
+                }
+
+                fn parse(text: &str) -> Option<Self> {
+                    text.parse().ok()
+                }
+
+                fn format(&self, _precision: Option<usize>) -> String {
+                    self.to_string()
+                }
+
+                fn from_f64(value: f64) -> Option<Self> {
+                    // Adding zero turns `-0` into `0`.
+                    Self::parse(&(value.round() + 0.0).to_string())
+                }
+
+                fn format_radix(&self, radix: Radix) -> Option<String> {
+                    let (is_negative, magnitude) = ($split)(*self);
+                    let digits = match radix {
+                        Radix::Decimal => format!("{magnitude}"),
+                        Radix::Hexadecimal => format!("{magnitude:X}"),
+                        Radix::Octal => format!("{magnitude:o}"),
+                        Radix::Binary => format!("{magnitude:b}"),
+                    };
+                    let sign = if is_negative { "-" } else { "" };
+
+                    Some(format!("{sign}{}{digits}", radix.prefix()))
+                }
+
+                fn parse_radix(text: &str, radix: Radix) -> Option<Self> {
+                    Self::from_str_radix(&normalize_radix(text, radix)?, radix.base()).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8 => |value| (false, value),
+    u16 => |value| (false, value),
+    u32 => |value| (false, value),
+    u64 => |value| (false, value),
+    u128 => |value| (false, value),
+    usize => |value| (false, value),
+    i8 => |value: i8| (value < 0, value.unsigned_abs()),
+    i16 => |value: i16| (value < 0, value.unsigned_abs()),
+    i32 => |value: i32| (value < 0, value.unsigned_abs()),
+    i64 => |value: i64| (value < 0, value.unsigned_abs()),
+    i128 => |value: i128| (value < 0, value.unsigned_abs()),
+    isize => |value: isize| (value < 0, value.unsigned_abs()),
+);
+
+/// Implements [`Number`] for primitive floating point types.
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Number for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn one() -> Self {
+                    1.0
+                }
+
+                fn add(&self, other: &Self) -> Self {
+                    self + other
+                }
+
+                fn sub(&self, other: &Self) -> Self {
+                    self - other
+                }
+
+                fn times_ten(&self) -> Self {
+                    self * 10.0
+                }
+
+                fn tenth(&self) -> Self {
+                    self / 10.0
+                }
+
+                fn is_fractional() -> bool {
+                    true
+                }
+
+                fn parse(text: &str) -> Option<Self> {
+                    text.parse().ok()
+                }
+
+                fn format(&self, precision: Option<usize>) -> String {
+                    precision.map_or_else(
+                        || {
+                            // Rounding to the significant digits of the type
+                            // drops the error of binary fractions.
+                            let digits = $t::DIGITS as usize - 1;
+                            format!("{self:.digits$e}")
+                                .parse::<Self>()
+                                .unwrap_or(*self)
+                                .to_string()
+                        },
+                        |precision| format!("{self:.precision$}"),
+                    )
+                }
+
+                fn format_exact(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// Returns the number of decimal places of a number formatted with `.` as
+/// decimal separator, e.g. `2` for `-1.25`.
+#[must_use]
+pub fn decimal_places(number: &str) -> usize {
+    number
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+/// Normalizes the text of a number in the given [`Radix`] for parsing with
+/// `from_str_radix`, e.g. `-0xff` to `-ff`.
+///
+/// The prefix is optional and its case is ignored. Returns `None` if no digits
+/// are left.
+#[must_use]
+pub fn normalize_radix(text: &str, radix: Radix) -> Option<String> {
+    let text = text.trim();
+    let (sign, digits) = text
+        .strip_prefix('-')
+        .map_or(("", text), |digits| ("-", digits));
+
+    let prefix = radix.prefix();
+    let digits = match digits.get(..prefix.len()) {
+        Some(start) if !prefix.is_empty() && start.eq_ignore_ascii_case(prefix) => {
+            &digits[prefix.len()..]
+        }
+        _ => digits,
+    };
+
+    if digits.is_empty() || digits.starts_with(['-', '+']) {
+        return None;
+    }
+
+    Some(format!("{sign}{digits}"))
+}
+
+/// Inserts the group separator between every three digits of the integer part
+/// of the formatted number.
+///
+/// The number may start with a `-` sign and contain a fractional part
+/// separated by the `decimal_separator`.
+#[must_use]
+pub fn group_digits(number: &str, decimal_separator: char, group_separator: char) -> String {
+    let (sign, number) = number
+        .strip_prefix('-')
+        .map_or(("", number), |number| ("-", number));
+
+    let (integer, fraction) = number
+        .find(decimal_separator)
+        .map_or((number, ""), |index| number.split_at(index));
+
+    let digits = integer.chars().count();
+    let mut grouped = String::with_capacity(number.len() + digits / 3 + 1);
+    grouped.push_str(sign);
+
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (digits - i) % 3 == 0 {
+            grouped.push(group_separator);
+        }
+        grouped.push(c);
+    }
+
+    grouped.push_str(fraction);
+    grouped
+}
+
+/// Evaluates a simple arithmetic expression like `12*4+3` or `=100-15%`.
+///
+/// Supported are numbers with `.` as decimal separator, `+`, `-`, `*`, `/`,
+/// parentheses and percentages. Adding or subtracting a percentage is relative
+/// to the left side, so `100-15%` results in `85`. A leading `=` is ignored.
+///
+/// Returns `None` if the expression is invalid or its result is not finite.
+#[must_use]
+pub fn evaluate(expression: &str) -> Option<f64> {
+    let expression = expression.trim();
+    let expression = expression.strip_prefix('=').unwrap_or(expression);
+
+    let mut parser = Parser {
+        chars: expression.chars().filter(|c| !c.is_whitespace()).peekable(),
+    };
+
+    let (value, is_percentage) = parser.expression()?;
+
+    if parser.chars.next().is_some() {
+        return None;
+    }
+
+    let value = if is_percentage { value / 100.0 } else { value };
+    value.is_finite().then_some(value)
+}
+
+/// A recursive descent parser for arithmetic expressions.
+///
+/// Every rule returns the value and whether it is a percentage.
+struct Parser<I: Iterator<Item = char>> {
+    /// The remaining characters of the expression.
+    chars: std::iter::Peekable<I>,
+}
+
+impl<I: Iterator<Item = char>> Parser<I> {
+    /// `expression = term { ("+" | "-") term }`
+    fn expression(&mut self) -> Option<(f64, bool)> {
+        let (mut value, mut is_percentage) = self.term()?;
+
+        while let Some(operator) = self.chars.next_if(|c| matches!(c, '+' | '-')) {
+            let left = if is_percentage { value / 100.0 } else { value };
+            let (right, right_is_percentage) = self.term()?;
+            let right = if right_is_percentage {
+                left * right / 100.0
+            } else {
+                right
+            };
+
+            value = if operator == '+' {
+                left + right
+            } else {
+                left - right
+            };
+            is_percentage = false;
+        }
+
+        Some((value, is_percentage))
+    }
+
+    /// `term = factor { ("*" | "/") factor }`
+    fn term(&mut self) -> Option<(f64, bool)> {
+        let (mut value, mut is_percentage) = self.factor()?;
+
+        while let Some(operator) = self.chars.next_if(|c| matches!(c, '*' | '/')) {
+            let left = if is_percentage { value / 100.0 } else { value };
+            let (right, right_is_percentage) = self.factor()?;
+            let right = if right_is_percentage {
+                right / 100.0
+            } else {
+                right
+            };
+
+            value = if operator == '*' {
+                left * right
+            } else if right == 0.0 {
+                return None;
+            } else {
+                left / right
+            };
+            is_percentage = false;
+        }
+
+        Some((value, is_percentage))
+    }
+
+    /// `factor = ("+" | "-") factor | primary [ "%" ]`
+    fn factor(&mut self) -> Option<(f64, bool)> {
+        if self.chars.next_if_eq(&'-').is_some() {
+            return self
+                .factor()
+                .map(|(value, is_percentage)| (-value, is_percentage));
+        }
+        if self.chars.next_if_eq(&'+').is_some() {
+            return self.factor();
+        }
+
+        let value = self.primary()?;
+        let is_percentage = self.chars.next_if_eq(&'%').is_some();
+
+        Some((value, is_percentage))
+    }
+
+    /// `primary = number | "(" expression ")"`
+    fn primary(&mut self) -> Option<f64> {
+        if self.chars.next_if_eq(&'(').is_some() {
+            let (value, is_percentage) = self.expression()?;
+            let value = if is_percentage { value / 100.0 } else { value };
+
+            return self.chars.next_if_eq(&')').map(|_| value);
+        }
+
+        let mut number = String::new();
+        while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+            number.push(c);
+        }
+
+        number.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decimal_places, evaluate, group_digits, Number, Radix};
+
+    #[test]
+    fn group_digits_test() {
+        assert_eq!(group_digits("0", '.', ','), "0");
+        assert_eq!(group_digits("123", '.', ','), "123");
+        assert_eq!(group_digits("1234", '.', ','), "1,234");
+        assert_eq!(group_digits("1234567.891", '.', ','), "1,234,567.891");
+        assert_eq!(group_digits("-123456", '.', ','), "-123,456");
+        assert_eq!(group_digits("-1234,5", ',', '.'), "-1.234,5");
+        assert_eq!(group_digits("100000", '.', ' '), "100 000");
+    }
+
+    #[test]
+    fn decimal_places_test() {
+        assert_eq!(decimal_places("12"), 0);
+        assert_eq!(decimal_places("0.1"), 1);
+        assert_eq!(decimal_places("-1.25"), 2);
+    }
+
+    #[test]
+    fn evaluate_test() {
+        assert_eq!(evaluate("42"), Some(42.0));
+        assert_eq!(evaluate("12*4+3"), Some(51.0));
+        assert_eq!(evaluate("1920/2"), Some(960.0));
+        assert_eq!(evaluate("2+3*4"), Some(14.0));
+        assert_eq!(evaluate("(2+3)*4"), Some(20.0));
+        assert_eq!(evaluate(" 1.5 * -2 "), Some(-3.0));
+        assert_eq!(evaluate("--3"), Some(3.0));
+        assert_eq!(evaluate("=100-15%"), Some(85.0));
+        assert_eq!(evaluate("200+10%"), Some(220.0));
+        assert_eq!(evaluate("50*10%"), Some(5.0));
+        assert_eq!(evaluate("15%"), Some(0.15));
+
+        assert_eq!(evaluate(""), None);
+        assert_eq!(evaluate("1/0"), None);
+        assert_eq!(evaluate("1+"), None);
+        assert_eq!(evaluate("(1+2"), None);
+        assert_eq!(evaluate("1+2)"), None);
+        assert_eq!(evaluate("1..2"), None);
+        assert_eq!(evaluate("abc"), None);
+    }
+
+    #[test]
+    fn number_test() {
+        assert_eq!(250_u8.add(&10), u8::MAX);
+        assert_eq!(5_u8.sub(&10), 0);
+        assert_eq!(i32::MIN.sub(&1), i32::MIN);
+        assert_eq!(5_u32.tenth(), 0);
+        assert!((0.5_f32.times_ten() - 5.0).abs() < f32::EPSILON);
+        assert_eq!(u32::from_f64(47.6), Some(48));
+        assert_eq!(i32::from_f64(-0.2), Some(0));
+        assert_eq!(u32::from_f64(-1.0), None);
+        assert_eq!(2.5_f64.format(Some(2)), "2.50");
+        assert_eq!((0.1_f64 + 0.2).format(None), "0.3");
+        assert_eq!((0.1_f32 * 3.0).format(None), "0.3");
+        assert_eq!(1e-7_f64.format(None), "0.0000001");
+        assert_eq!(1_234_567.5_f64.format(None), "1234567.5");
+        assert_eq!(99_999.99_f32.format(None), "100000");
+        assert_eq!(f32::parse(&99_999.99_f32.format_exact()), Some(99_999.99));
+        assert_eq!(
+            f32::parse(&16_777_215_f32.format_exact()),
+            Some(16_777_215.0)
+        );
+        assert_eq!((-42_i64).format_exact(), "-42");
+        assert_eq!(2_i32.format(Some(2)), "2");
+        assert!(!u8::is_fractional());
+        assert!(f64::is_fractional());
+        assert_eq!(1.5_f64.format_radix(Radix::Hexadecimal), None);
+    }
+
+    #[test]
+    fn format_radix_test() {
+        assert_eq!(
+            0_u8.format_radix(Radix::Hexadecimal).as_deref(),
+            Some("0x0")
+        );
+        assert_eq!(
+            255_u8.format_radix(Radix::Hexadecimal).as_deref(),
+            Some("0xFF")
+        );
+        assert_eq!(
+            10_u32.format_radix(Radix::Binary).as_deref(),
+            Some("0b1010")
+        );
+        assert_eq!(8_u32.format_radix(Radix::Octal).as_deref(), Some("0o10"));
+        assert_eq!(255_i32.format_radix(Radix::Decimal).as_deref(), Some("255"));
+        assert_eq!(
+            (-255_i32).format_radix(Radix::Hexadecimal).as_deref(),
+            Some("-0xFF")
+        );
+        assert_eq!(
+            i8::MIN.format_radix(Radix::Binary).as_deref(),
+            Some("-0b10000000")
+        );
+    }
+
+    #[test]
+    fn parse_radix_test() {
+        assert_eq!(u8::parse_radix("0xFF", Radix::Hexadecimal), Some(255));
+        assert_eq!(u8::parse_radix("0Xff", Radix::Hexadecimal), Some(255));
+        assert_eq!(u8::parse_radix("ff", Radix::Hexadecimal), Some(255));
+        assert_eq!(u32::parse_radix("0b1010", Radix::Binary), Some(10));
+        assert_eq!(u32::parse_radix("0o17", Radix::Octal), Some(15));
+        assert_eq!(i32::parse_radix("-0xFF", Radix::Hexadecimal), Some(-255));
+        assert_eq!(i8::parse_radix("-0b10000000", Radix::Binary), Some(i8::MIN));
+
+        assert_eq!(u8::parse_radix("0x", Radix::Hexadecimal), None);
+        assert_eq!(u8::parse_radix("0x100", Radix::Hexadecimal), None);
+        assert_eq!(u8::parse_radix("-0x1", Radix::Hexadecimal), None);
+        assert_eq!(u32::parse_radix("0b102", Radix::Binary), None);
+        assert_eq!(i32::parse_radix("0x-1", Radix::Hexadecimal), None);
+    }
+}
+
+#[cfg(test)]
+mod decimal_tests {
+    use super::{evaluate, Number};
+    use crate::native::number_input::NumberInput;
+
+    /// A fixed-point decimal with two decimal places, which is neither
+    /// `Copy` nor a primitive.
+    #[derive(Clone, Debug, PartialEq, PartialOrd)]
+    struct Decimal {
+        cents: i64,
+    }
+
+    impl Number for Decimal {
+        fn zero() -> Self {
+            Self { cents: 0 }
+        }
+
+        fn one() -> Self {
+            Self { cents: 100 }
+        }
+
+        fn add(&self, other: &Self) -> Self {
+            Self {
+                cents: self.cents.saturating_add(other.cents),
+            }
+        }
+
+        fn sub(&self, other: &Self) -> Self {
+            Self {
+                cents: self.cents.saturating_sub(other.cents),
+            }
+        }
+
+        fn times_ten(&self) -> Self {
+            Self {
+                cents: self.cents.saturating_mul(10),
+            }
+        }
+
+        fn tenth(&self) -> Self {
+            Self {
+                cents: self.cents / 10,
+            }
+        }
+
+        fn is_fractional() -> bool {
+            true
+        }
+
+        fn parse(text: &str) -> Option<Self> {
+            let (sign, text) = text.strip_prefix('-').map_or((1, text), |text| (-1, text));
+            let (units, fraction) = text.split_once('.').unwrap_or((text, ""));
+            if units.is_empty() || fraction.len() > 2 {
+                return None;
+            }
+            let units: i64 = units.parse().ok()?;
+            let fraction: i64 = format!("{fraction:0<2}").parse().ok()?;
+            Some(Self {
+                cents: sign * (units * 100 + fraction),
+            })
+        }
+
+        fn format(&self, precision: Option<usize>) -> String {
+            let sign = if self.cents < 0 { "-" } else { "" };
+            let precision = precision.unwrap_or(2);
+
+            // Round half away from zero to at most the two stored places.
+            let places = precision.min(2);
+            let divisor = 10_u64.pow(2 - places as u32);
+            let scaled = (self.cents.unsigned_abs() + divisor / 2) / divisor;
+            let scale = 10_u64.pow(places as u32);
+
+            if precision == 0 {
+                return format!("{sign}{scaled}");
+            }
+
+            format!(
+                "{sign}{}.{:0places$}{:0<padding$}",
+                scaled / scale,
+                scaled % scale,
+                "",
+                padding = precision - places
+            )
+        }
+    }
+
+    #[test]
+    fn decimal_number_test() {
+        let price = Decimal::parse("12.5").expect("valid decimal");
+        assert_eq!(price, Decimal { cents: 1250 });
+        assert_eq!(price.format(None), "12.50");
+        assert_eq!(price.format(Some(3)), "12.500");
+        assert_eq!(price.format(Some(1)), "12.5");
+        assert_eq!(price.format(Some(0)), "13");
+        assert_eq!(Decimal { cents: 1249 }.format(Some(0)), "12");
+        assert_eq!(Decimal { cents: -1255 }.format(Some(1)), "-12.6");
+        assert_eq!(Decimal { cents: 7 }.format(Some(1)), "0.1");
+        assert_eq!(price.sub(&Decimal::one().times_ten()).format(None), "2.50");
+        assert_eq!(
+            Decimal::parse("-0.05").map(|d| d.format(None)).as_deref(),
+            Some("-0.05")
+        );
+        assert_eq!(Decimal::parse("1.234"), None);
+        assert_eq!(price.to_f64(), Some(12.5));
+    }
+
+    #[test]
+    fn decimal_evaluate_test() {
+        let total = evaluate("12.5*2+10%").and_then(Decimal::from_f64);
+        assert_eq!(total, Some(Decimal { cents: 2750 }));
+    }
+
+    #[test]
+    fn decimal_number_input_test() {
+        // A custom type satisfies the bounds of the widget.
+        let _input: NumberInput<'_, Decimal, Decimal> =
+            NumberInput::new(Decimal::one(), Decimal { cents: 10_000 }, |value| value)
+                .step(Decimal { cents: 25 })
+                .precision(2);
+    }
+}
@@ -0,0 +1,97 @@
+//! Match search queries against the text of options.
+//!
+//! *This API requires the following crate features to be activated: `selection_list`*
+
+/// The way a search query is matched against a text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SearchMode {
+    /// The text has to contain the query.
+    #[default]
+    Substring,
+    /// The text has to contain the characters of the query in the same order,
+    /// but not necessarily next to each other.
+    Fuzzy,
+}
+
+impl SearchMode {
+    /// Matches the query case-insensitively against the text and returns the
+    /// positions of the matched characters of the text, or `None` if the text
+    /// does not match.
+    #[must_use]
+    pub fn find(self, text: &str, query: &str) -> Option<Vec<usize>> {
+        match self {
+            Self::Substring => find_substring(text, query),
+            Self::Fuzzy => find_fuzzy(text, query),
+        }
+    }
+}
+
+/// Returns the positions of the characters of the first occurrence of the
+/// query in the text.
+fn find_substring(text: &str, query: &str) -> Option<Vec<usize>> {
+    let text: Vec<char> = text.chars().collect();
+    let query: Vec<char> = query.chars().collect();
+
+    if query.is_empty() {
+        return Some(Vec::new());
+    }
+
+    text.windows(query.len())
+        .position(|window| {
+            window
+                .iter()
+                .zip(&query)
+                .all(|(&a, &b)| eq_ignore_case(a, b))
+        })
+        .map(|start| (start..start + query.len()).collect())
+}
+
+/// Returns the positions of the first characters of the text matching the
+/// characters of the query in order.
+fn find_fuzzy(text: &str, query: &str) -> Option<Vec<usize>> {
+    let mut positions = Vec::new();
+    let mut text = text.chars().enumerate();
+
+    for q in query.chars() {
+        let (position, _) = text.by_ref().find(|&(_, c)| eq_ignore_case(c, q))?;
+        positions.push(position);
+    }
+
+    Some(positions)
+}
+
+/// Compares two characters case-insensitively.
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchMode;
+
+    #[test]
+    fn substring_test() {
+        let mode = SearchMode::Substring;
+
+        assert_eq!(mode.find("Hello", ""), Some(vec![]));
+        assert_eq!(mode.find("Hello", "ell"), Some(vec![1, 2, 3]));
+        assert_eq!(mode.find("Hello", "HELLO"), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(mode.find("Grüße", "ÜSS"), None);
+        assert_eq!(mode.find("Grüße", "ÜSE"), None);
+        assert_eq!(mode.find("Grüße", "üß"), Some(vec![2, 3]));
+        assert_eq!(mode.find("Hello", "hlo"), None);
+        assert_eq!(mode.find("Hi", "Hello"), None);
+    }
+
+    #[test]
+    fn fuzzy_test() {
+        let mode = SearchMode::Fuzzy;
+
+        assert_eq!(mode.find("Hello", ""), Some(vec![]));
+        assert_eq!(mode.find("Hello", "hlo"), Some(vec![0, 2, 4]));
+        assert_eq!(mode.find("Hello", "ll"), Some(vec![2, 3]));
+        assert_eq!(mode.find("Hello", "lll"), None);
+        assert_eq!(mode.find("Hello", "oh"), None);
+        assert_eq!(mode.find("New York", "NY"), Some(vec![0, 4]));
+    }
+}
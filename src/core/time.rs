@@ -1,12 +1,21 @@
 //! Use a time picker as an input element for picking times.
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+    str::FromStr,
+    time::Duration,
+};
 
-use chrono::{Local, Timelike};
+#[cfg(feature = "chrono")]
+use chrono::Timelike;
+
+/// The number of seconds of a day.
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
 
 /// The time value
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Time {
     /// The time value containing hour, minute and period.
     Hm {
@@ -35,41 +44,29 @@ impl Time {
     /// Creates a new time (hours, minutes) from the current timestamp.
     #[must_use]
     pub fn now_hm(use_24h: bool) -> Self {
-        let now = Local::now().naive_local().time();
-
-        let (hour, period) = if use_24h {
-            (now.hour(), Period::H24)
-        } else {
-            let (period, hour12) = now.hour12();
-
-            (hour12, if period { Period::Pm } else { Period::Am })
+        let now = local_now();
+        let time = Self::Hm {
+            hour: now.hour24(),
+            minute: now.minute(),
+            period: Period::H24,
         };
 
-        Self::Hm {
-            hour,
-            minute: now.minute(),
-            period,
+        if use_24h {
+            time
+        } else {
+            time.to_12h()
         }
     }
 
     /// Creates a new time (hours, minutes, seconds) from the current timestamp.
     #[must_use]
     pub fn now_hms(use_24h: bool) -> Self {
-        let now = Local::now().naive_local().time();
+        let now = local_now();
 
-        let (hour, period) = if use_24h {
-            (now.hour(), Period::H24)
+        if use_24h {
+            now
         } else {
-            let (period, hour12) = now.hour12();
-
-            (hour12, if period { Period::Pm } else { Period::Am })
-        };
-
-        Self::Hms {
-            hour,
-            minute: now.minute(),
-            second: now.second(),
-            period,
+            now.to_12h()
         }
     }
 
@@ -93,6 +90,333 @@ impl Time {
             period,
         }
     }
+
+    /// Returns the hour of the time in the 24 hour format (0 - 23).
+    #[must_use]
+    pub const fn hour24(&self) -> u32 {
+        let (hour, period) = match self {
+            Self::Hm { hour, period, .. } | Self::Hms { hour, period, .. } => (*hour, *period),
+        };
+
+        match period {
+            Period::H24 => hour,
+            Period::Am => hour % 12,
+            Period::Pm => hour % 12 + 12,
+        }
+    }
+
+    /// Returns whether the time is in the afternoon and the hour in the 12
+    /// hour format (1 - 12).
+    #[must_use]
+    pub const fn hour12(&self) -> (bool, u32) {
+        let hour = self.hour24();
+        let hour12 = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+
+        (hour >= 12, hour12)
+    }
+
+    /// Returns the minute of the time.
+    #[must_use]
+    pub const fn minute(&self) -> u32 {
+        match self {
+            Self::Hm { minute, .. } | Self::Hms { minute, .. } => *minute,
+        }
+    }
+
+    /// Returns the second of the time. This is always `0` for [`Time::Hm`].
+    #[must_use]
+    pub const fn second(&self) -> u32 {
+        match self {
+            Self::Hm { .. } => 0,
+            Self::Hms { second, .. } => *second,
+        }
+    }
+
+    /// Returns the period of the time.
+    #[must_use]
+    pub const fn period(&self) -> Period {
+        match self {
+            Self::Hm { period, .. } | Self::Hms { period, .. } => *period,
+        }
+    }
+
+    /// Returns the number of seconds passed since midnight.
+    #[must_use]
+    pub const fn num_seconds_from_midnight(&self) -> u32 {
+        self.hour24() * 3600 + self.minute() * 60 + self.second()
+    }
+
+    /// Converts the time into the 24 hour format.
+    #[must_use]
+    pub const fn to_24h(self) -> Self {
+        self.with_hour24(self.hour24(), Period::H24)
+    }
+
+    /// Converts the time into the 12 hour format using AM/PM.
+    #[must_use]
+    pub const fn to_12h(self) -> Self {
+        let hour = self.hour24();
+        self.with_hour24(hour, if hour < 12 { Period::Am } else { Period::Pm })
+    }
+
+    /// Formats the time with a `strftime` pattern of `chrono`, e.g. `"%H:%M"`
+    /// or `"%I:%M:%S %p"`.
+    ///
+    /// An invalid time or pattern is formatted like the [`Display`] output.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn format_strftime(&self, pattern: &str) -> String {
+        use std::fmt::Write;
+
+        let mut formatted = String::new();
+        chrono::NaiveTime::from_hms_opt(self.hour24(), self.minute(), self.second())
+            .and_then(|time| write!(formatted, "{}", time.format(pattern)).ok())
+            .map_or_else(|| self.to_string(), |()| formatted)
+    }
+
+    /// Formats the time with a format description of `time`, e.g.
+    /// `"[hour]:[minute]"` or `"[hour repr:12]:[minute] [period]"`.
+    ///
+    /// An invalid time or format description is formatted like the
+    /// [`Display`] output.
+    #[cfg(feature = "time")]
+    #[must_use]
+    pub fn format_description(&self, description: &str) -> String {
+        let items = time::format_description::parse_borrowed::<2>(description).ok();
+        let time = time::Time::from_hms(
+            self.hour24() as u8,
+            self.minute() as u8,
+            self.second() as u8,
+        )
+        .ok();
+
+        items
+            .zip(time)
+            .and_then(|(items, time)| time.format(&items).ok())
+            .unwrap_or_else(|| self.to_string())
+    }
+
+    /// Replaces the hour of the time by the given 24 hour format hour while
+    /// keeping the variant and representing it in the given period style.
+    pub(crate) const fn with_hour24(self, hour: u32, period: Period) -> Self {
+        let hour = match period {
+            Period::H24 => hour,
+            Period::Am | Period::Pm => match hour % 12 {
+                0 => 12,
+                hour => hour,
+            },
+        };
+
+        match self {
+            Self::Hm { minute, .. } => Self::Hm {
+                hour,
+                minute,
+                period,
+            },
+            Self::Hms { minute, second, .. } => Self::Hms {
+                hour,
+                minute,
+                second,
+                period,
+            },
+        }
+    }
+
+    /// Replaces the minute of the time.
+    pub(crate) const fn with_minute(self, minute: u32) -> Self {
+        match self {
+            Self::Hm { hour, period, .. } => Self::Hm {
+                hour,
+                minute,
+                period,
+            },
+            Self::Hms {
+                hour,
+                second,
+                period,
+                ..
+            } => Self::Hms {
+                hour,
+                minute,
+                second,
+                period,
+            },
+        }
+    }
+
+    /// Replaces the second of the time. A [`Time::Hm`] stays unchanged.
+    pub(crate) const fn with_second(self, second: u32) -> Self {
+        match self {
+            Self::Hm { .. } => self,
+            Self::Hms {
+                hour,
+                minute,
+                period,
+                ..
+            } => Self::Hms {
+                hour,
+                minute,
+                second,
+                period,
+            },
+        }
+    }
+
+    /// Creates a time of the same variant and period style from the number
+    /// of seconds since midnight.
+    const fn with_seconds_from_midnight(self, seconds: u32) -> Self {
+        let seconds = seconds % SECONDS_PER_DAY;
+        let hour = seconds / 3600;
+        let minute = seconds / 60 % 60;
+
+        let time = match self {
+            Self::Hm { .. } => Self::Hm {
+                hour,
+                minute,
+                period: Period::H24,
+            },
+            Self::Hms { .. } => Self::Hms {
+                hour,
+                minute,
+                second: seconds % 60,
+                period: Period::H24,
+            },
+        };
+
+        match self.period() {
+            Period::H24 => time,
+            Period::Am | Period::Pm => time.to_12h(),
+        }
+    }
+}
+
+/// Gets the current local time from `chrono` in the 24 hour format.
+#[cfg(feature = "chrono")]
+fn local_now() -> Time {
+    chrono::Local::now().time().into()
+}
+
+/// Gets the current local time from `time` in the 24 hour format, falling
+/// back to UTC if the local offset is unknown.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+fn local_now() -> Time {
+    time::OffsetDateTime::now_local()
+        .unwrap_or_else(|_| time::OffsetDateTime::now_utc())
+        .time()
+        .into()
+}
+
+impl Add<Duration> for Time {
+    type Output = Self;
+
+    /// Adds the duration to the time, wrapping around at midnight.
+    ///
+    /// Parts of the duration smaller than the precision of the time are
+    /// dropped.
+    fn add(self, rhs: Duration) -> Self::Output {
+        let seconds = (rhs.as_secs() % u64::from(SECONDS_PER_DAY)) as u32;
+        self.with_seconds_from_midnight(self.num_seconds_from_midnight() + seconds)
+    }
+}
+
+impl Sub<Duration> for Time {
+    type Output = Self;
+
+    /// Subtracts the duration from the time, wrapping around at midnight.
+    ///
+    /// Parts of the duration smaller than the precision of the time are
+    /// dropped.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        let seconds = (rhs.as_secs() % u64::from(SECONDS_PER_DAY)) as u32;
+        self.with_seconds_from_midnight(
+            self.num_seconds_from_midnight() + SECONDS_PER_DAY - seconds,
+        )
+    }
+}
+
+/// An error which can be returned when parsing a [`Time`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseTimeError {
+    /// The string does not match the expected format.
+    InvalidFormat,
+    /// A component of the value is out of its range.
+    OutOfRange,
+}
+
+impl Display for ParseTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::InvalidFormat => "invalid format",
+                Self::OutOfRange => "value out of range",
+            }
+        )
+    }
+}
+
+impl std::error::Error for ParseTimeError {}
+
+impl FromStr for Time {
+    type Err = ParseTimeError;
+
+    /// Parses a time in the format `HH:MM` or `HH:MM:SS`, optionally followed
+    /// by `AM` or `PM` for the 12 hour format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let upper = s.to_ascii_uppercase();
+
+        let (s, period) = if upper.ends_with("AM") {
+            (s[..s.len() - 2].trim_end(), Period::Am)
+        } else if upper.ends_with("PM") {
+            (s[..s.len() - 2].trim_end(), Period::Pm)
+        } else {
+            (s, Period::H24)
+        };
+
+        let parts = s
+            .split(':')
+            .map(|part| {
+                if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(ParseTimeError::InvalidFormat);
+                }
+                part.parse::<u32>().ok().ok_or(ParseTimeError::OutOfRange)
+            })
+            .collect::<Result<Vec<u32>, ParseTimeError>>()?;
+
+        let time = match parts[..] {
+            [hour, minute] => Self::Hm {
+                hour,
+                minute,
+                period,
+            },
+            [hour, minute, second] => Self::Hms {
+                hour,
+                minute,
+                second,
+                period,
+            },
+            _ => return Err(ParseTimeError::InvalidFormat),
+        };
+
+        let (hour, minute) = match time {
+            Self::Hm { hour, minute, .. } | Self::Hms { hour, minute, .. } => (hour, minute),
+        };
+        let hour_valid = match period {
+            Period::H24 => hour < 24,
+            Period::Am | Period::Pm => (1..=12).contains(&hour),
+        };
+
+        if hour_valid && minute < 60 && time.second() < 60 {
+            Ok(time)
+        } else {
+            Err(ParseTimeError::OutOfRange)
+        }
+    }
 }
 
 impl Display for Time {
@@ -142,6 +466,7 @@ impl Display for Period {
     }
 }
 
+#[cfg(feature = "chrono")]
 impl From<Time> for chrono::NaiveTime {
     fn from(time: Time) -> Self {
         let (h, m, s, p) = match time {
@@ -169,6 +494,31 @@ impl From<Time> for chrono::NaiveTime {
     }
 }
 
+#[cfg(feature = "time")]
+impl From<Time> for time::Time {
+    fn from(time: Time) -> Self {
+        Self::from_hms(
+            time.hour24() as u8,
+            time.minute() as u8,
+            time.second() as u8,
+        )
+        .expect("Time Conversion failed. H, M, or S was too large.")
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Time> for Time {
+    fn from(time: time::Time) -> Self {
+        Self::Hms {
+            hour: u32::from(time.hour()),
+            minute: u32::from(time.minute()),
+            second: u32::from(time.second()),
+            period: Period::H24,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
 impl From<chrono::NaiveTime> for Time {
     fn from(time: chrono::NaiveTime) -> Self {
         Self::Hms {
@@ -183,10 +533,14 @@ impl From<chrono::NaiveTime> for Time {
 #[cfg(test)]
 
 mod tests {
+    use std::time::Duration;
+
+    #[cfg(feature = "chrono")]
     use chrono::NaiveTime;
 
-    use super::{Period, Time};
+    use super::{ParseTimeError, Period, Time};
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn time_to_naive() {
         let time = Time::Hms {
@@ -279,4 +633,213 @@ mod tests {
             NaiveTime::from_hms_opt(17, 52, 0).expect("Time Conversion failed")
         );
     }
+
+    #[test]
+    fn time_from_str() {
+        assert_eq!(
+            "08:52".parse::<Time>(),
+            Ok(Time::Hm {
+                hour: 8,
+                minute: 52,
+                period: Period::H24,
+            })
+        );
+        assert_eq!(
+            "23:48:39".parse::<Time>(),
+            Ok(Time::Hms {
+                hour: 23,
+                minute: 48,
+                second: 39,
+                period: Period::H24,
+            })
+        );
+        assert_eq!(
+            " 8:52:17 pm".parse::<Time>(),
+            Ok(Time::Hms {
+                hour: 8,
+                minute: 52,
+                second: 17,
+                period: Period::Pm,
+            })
+        );
+        assert_eq!(
+            "12:00AM".parse::<Time>(),
+            Ok(Time::Hm {
+                hour: 12,
+                minute: 0,
+                period: Period::Am,
+            })
+        );
+
+        assert_eq!("24:00".parse::<Time>(), Err(ParseTimeError::OutOfRange));
+        assert_eq!("0:00 AM".parse::<Time>(), Err(ParseTimeError::OutOfRange));
+        assert_eq!("12:60".parse::<Time>(), Err(ParseTimeError::OutOfRange));
+        assert_eq!("12".parse::<Time>(), Err(ParseTimeError::InvalidFormat));
+        assert_eq!("12:-1".parse::<Time>(), Err(ParseTimeError::InvalidFormat));
+        assert_eq!(
+            "1:2:3:4".parse::<Time>(),
+            Err(ParseTimeError::InvalidFormat)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn time_format_strftime() {
+        let time = Time::Hms {
+            hour: 8,
+            minute: 52,
+            second: 17,
+            period: Period::Pm,
+        };
+
+        assert_eq!(time.format_strftime("%H:%M:%S"), "20:52:17");
+        assert_eq!(time.format_strftime("%I:%M %p"), "08:52 PM");
+        assert_eq!(time.format_strftime("%Q"), "08:52:17 PM");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_format_description() {
+        let time = Time::Hms {
+            hour: 8,
+            minute: 52,
+            second: 17,
+            period: Period::Pm,
+        };
+
+        assert_eq!(
+            time.format_description("[hour]:[minute]:[second]"),
+            "20:52:17"
+        );
+        assert_eq!(time.format_description("[hour"), "08:52:17 PM");
+        assert_eq!(
+            time.format_description("[hour repr:12]:[minute] [period]"),
+            "08:52 PM"
+        );
+    }
+
+    #[test]
+    fn time_normalization() {
+        let time = Time::Hm {
+            hour: 12,
+            minute: 30,
+            period: Period::Am,
+        };
+        assert_eq!(time.hour24(), 0);
+        assert_eq!(
+            time.to_24h(),
+            Time::Hm {
+                hour: 0,
+                minute: 30,
+                period: Period::H24,
+            }
+        );
+
+        let time = Time::Hms {
+            hour: 13,
+            minute: 5,
+            second: 9,
+            period: Period::H24,
+        };
+        assert_eq!(
+            time.to_12h(),
+            Time::Hms {
+                hour: 1,
+                minute: 5,
+                second: 9,
+                period: Period::Pm,
+            }
+        );
+        assert_eq!(time.to_12h().to_24h(), time);
+
+        let time = Time::default_hm(Period::H24);
+        assert_eq!(
+            time.to_12h(),
+            Time::Hm {
+                hour: 12,
+                minute: 0,
+                period: Period::Am,
+            }
+        );
+        assert_eq!(time.hour12(), (false, 12));
+        assert_eq!(time.to_12h().hour12(), (false, 12));
+
+        let time = Time::Hm {
+            hour: 12,
+            minute: 15,
+            period: Period::Pm,
+        };
+        assert_eq!(time.hour12(), (true, 12));
+        assert_eq!(time.to_24h().hour12(), (true, 12));
+    }
+
+    #[test]
+    fn time_arithmetic() {
+        const SECONDS_PER_HOUR: u64 = 3600;
+
+        let time = Time::Hms {
+            hour: 23,
+            minute: 59,
+            second: 30,
+            period: Period::H24,
+        };
+        assert_eq!(
+            time + Duration::from_secs(45),
+            Time::Hms {
+                hour: 0,
+                minute: 0,
+                second: 15,
+                period: Period::H24,
+            }
+        );
+        assert_eq!(
+            time - Duration::from_secs(24 * SECONDS_PER_HOUR + 30),
+            Time::Hms {
+                hour: 23,
+                minute: 59,
+                second: 0,
+                period: Period::H24,
+            }
+        );
+
+        let time = Time::Hm {
+            hour: 11,
+            minute: 30,
+            period: Period::Am,
+        };
+        assert_eq!(
+            time + Duration::from_secs(SECONDS_PER_HOUR),
+            Time::Hm {
+                hour: 12,
+                minute: 30,
+                period: Period::Pm,
+            }
+        );
+        assert_eq!(
+            time - Duration::from_secs(12 * SECONDS_PER_HOUR),
+            Time::Hm {
+                hour: 11,
+                minute: 30,
+                period: Period::Pm,
+            }
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_to_time_crate() {
+        let time = Time::Hms {
+            hour: 8,
+            minute: 52,
+            second: 17,
+            period: Period::Pm,
+        };
+
+        let converted: time::Time = time.into();
+        assert_eq!(
+            converted,
+            time::Time::from_hms(20, 52, 17).expect("Time Conversion failed")
+        );
+        assert_eq!(Time::from(converted), time.to_24h());
+    }
 }
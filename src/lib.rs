@@ -70,6 +70,10 @@ mod platform {
     #[cfg(feature = "card")]
     pub use {crate::native::card, crate::style::CardStyles, card::Card};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "clock")]
+    pub use {crate::native::clock, crate::style::ClockStyle, clock::Clock};
+
     #[doc(no_inline)]
     #[cfg(feature = "color_picker")]
     pub use {crate::native::color_picker, color_picker::ColorPicker};
@@ -98,7 +102,7 @@ mod platform {
     pub use {
         crate::native::tab_bar,
         crate::style::TabBarStyles,
-        tab_bar::{TabBar, TabLabel},
+        tab_bar::{TabBar, TabLabel, TabOptions},
     };
 
     #[doc(no_inline)]
@@ -119,7 +123,8 @@ mod platform {
     #[doc(no_inline)]
     #[cfg(feature = "number_input")]
     pub use {
-        crate::native::number_input, crate::style::NumberInputStyles, number_input::NumberInput,
+        crate::native::number_input, crate::style::NumberInputStyles, number_input::Number,
+        number_input::NumberInput,
     };
 
     #[doc(no_inline)]
@@ -129,6 +134,10 @@ mod platform {
         selection_list::SelectionList,
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "combo_box")]
+    pub use {crate::native::combo_box, combo_box::ComboBox};
+
     #[doc(no_inline)]
     #[cfg(feature = "split")]
     pub use {crate::native::split, crate::style::SplitStyles, split::Split};
@@ -0,0 +1,371 @@
+//! Use a clock to display the current time.
+//!
+//! *This API requires the following crate features to be activated: `clock`*
+use iced_widget::{
+    canvas::{self, LineCap, Path, Stroke, Style},
+    core::{
+        event,
+        layout::{Limits, Node},
+        mouse::Cursor,
+        renderer,
+        widget::tree::{self, Tag, Tree},
+        window, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Renderer as _, Shell,
+        Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+};
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::core::clock::{
+    self, HOUR_RADIUS_PERCENTAGE, HOUR_RADIUS_PERCENTAGE_NO_SECONDS, MINUTE_RADIUS_PERCENTAGE,
+    MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, SECOND_RADIUS_PERCENTAGE,
+};
+
+pub use crate::core::time::{Period, Time};
+
+pub use crate::style::clock::{Appearance, ClockStyle, StyleSheet};
+
+/// The outer radius of the tick marks based on the clock's size.
+const TICK_OUTER_PERCENTAGE: f32 = 0.95;
+/// The inner radius of the minute tick marks based on the clock's size.
+const MINUTE_TICK_INNER_PERCENTAGE: f32 = 0.9;
+/// The inner radius of the hour tick marks based on the clock's size.
+const HOUR_TICK_INNER_PERCENTAGE: f32 = 0.82;
+
+/// A read-only analog clock showing the current local time or a fixed time.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Clock;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+/// }
+///
+/// let clock = Clock::new().show_seconds(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Clock<Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The width of the [`Clock`].
+    width: Length,
+    /// The height of the [`Clock`].
+    height: Length,
+    /// The fixed time to show. The current local time is shown if `None`.
+    time: Option<Time>,
+    /// Toggle the second hand of the [`Clock`].
+    show_seconds: bool,
+    /// Toggle the tick marks of the [`Clock`].
+    show_ticks: bool,
+    /// The style of the [`Clock`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme> Clock<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Clock`] showing the current local time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`Clock`] always showing the given time.
+    #[must_use]
+    pub fn with_time(time: impl Into<Time>) -> Self {
+        Self {
+            time: Some(time.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the width of the [`Clock`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Clock`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Shows or hides the second hand of the [`Clock`].
+    #[must_use]
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Shows or hides the tick marks of the [`Clock`].
+    #[must_use]
+    pub fn show_ticks(mut self, show_ticks: bool) -> Self {
+        self.show_ticks = show_ticks;
+        self
+    }
+
+    /// Sets the style of the [`Clock`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the time the [`Clock`] should currently show.
+    fn current_time(&self) -> Time {
+        let time = self.time.unwrap_or_else(|| Time::now_hms(true));
+
+        // Drop everything the hands are not able to show, so that the cache
+        // only needs to be cleared if the clock visibly changes.
+        Time::Hms {
+            hour: time.hour24(),
+            minute: time.minute(),
+            second: if self.show_seconds { time.second() } else { 0 },
+            period: Period::H24,
+        }
+    }
+}
+
+impl<Theme> Default for Clock<Theme>
+where
+    Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self {
+            width: Length::Fixed(100.0),
+            height: Length::Fixed(100.0),
+            time: None,
+            show_seconds: true,
+            show_ticks: true,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+}
+
+/// The state of the [`Clock`].
+struct State {
+    /// The time currently drawn.
+    time: Time,
+    /// The cache of the clock face and hands.
+    cache: canvas::Cache,
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Clock<Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            time: self.current_time(),
+            cache: canvas::Cache::new(),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.time.is_some() {
+            let time = self.current_time();
+            if state.time != time {
+                state.time = time;
+                state.cache.clear();
+            }
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(self.width)
+                .height(self.height)
+                .resolve(Size::new(f32::INFINITY, f32::INFINITY)),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if self.time.is_some() {
+            return event::Status::Ignored;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = state.state.downcast_mut::<State>();
+
+            let time = self.current_time();
+            if state.time != time {
+                state.time = time;
+                state.cache.clear();
+            }
+
+            // Wake up right at the start of the next second.
+            let nanoseconds = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since_epoch| since_epoch.subsec_nanos());
+            shell.request_redraw(window::RedrawRequest::At(
+                now + Duration::from_nanos(u64::from(1_000_000_000 - nanoseconds)),
+            ));
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = state.state.downcast_ref::<State>();
+        let appearance = theme.active(&self.style);
+
+        let size = bounds.width.min(bounds.height);
+        let face = Rectangle {
+            x: bounds.center_x() - size / 2.0,
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: face,
+                border_radius: (size / 2.0).into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let geometry = state.cache.draw(renderer, bounds.size(), |frame| {
+            let center = frame.center();
+            let radius = frame.width().min(frame.height()) * 0.5;
+
+            if self.show_ticks {
+                let outer_points = clock::circle_points(radius * TICK_OUTER_PERCENTAGE, center, 60);
+                let minute_points =
+                    clock::circle_points(radius * MINUTE_TICK_INNER_PERCENTAGE, center, 60);
+                let hour_points =
+                    clock::circle_points(radius * HOUR_TICK_INNER_PERCENTAGE, center, 60);
+
+                for (i, outer) in outer_points.iter().enumerate() {
+                    let (inner, width) = if i % 5 == 0 {
+                        (hour_points[i], appearance.hand_width * 0.5)
+                    } else {
+                        (minute_points[i], appearance.hand_width * 0.25)
+                    };
+
+                    frame.stroke(
+                        &Path::line(inner, *outer),
+                        hand_stroke(appearance.tick_color, width),
+                    );
+                }
+            }
+
+            let (hour_radius, minute_radius) = if self.show_seconds {
+                (
+                    radius * HOUR_RADIUS_PERCENTAGE,
+                    radius * MINUTE_RADIUS_PERCENTAGE,
+                )
+            } else {
+                (
+                    radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
+                    radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
+                )
+            };
+
+            let seconds = state.time.second() as f32;
+            let minutes = state.time.minute() as f32 + seconds / 60.0;
+            let hours = (state.time.hour24() % 12) as f32 + minutes / 60.0;
+
+            let hand = hand_stroke(appearance.hand_color, appearance.hand_width);
+            frame.stroke(
+                &Path::line(center, clock::hand_point(hour_radius, center, hours / 12.0)),
+                hand.clone(),
+            );
+            frame.stroke(
+                &Path::line(
+                    center,
+                    clock::hand_point(minute_radius, center, minutes / 60.0),
+                ),
+                hand,
+            );
+
+            if self.show_seconds {
+                frame.stroke(
+                    &Path::line(
+                        center,
+                        clock::hand_point(
+                            radius * SECOND_RADIUS_PERCENTAGE,
+                            center,
+                            seconds / 60.0,
+                        ),
+                    ),
+                    hand_stroke(appearance.second_hand_color, appearance.second_hand_width),
+                );
+            }
+
+            frame.fill(
+                &Path::circle(center, appearance.hand_width),
+                appearance.hand_color,
+            );
+        });
+
+        let translation = Vector::new(bounds.x, bounds.y);
+        renderer.with_translation(translation, |renderer| {
+            renderer.draw(vec![geometry]);
+        });
+    }
+}
+
+/// Creates the stroke of a hand or tick mark of the [`Clock`].
+fn hand_stroke(color: Color, width: f32) -> Stroke<'static> {
+    Stroke {
+        style: Style::Solid(color),
+        width,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    }
+}
+
+impl<'a, Message, Theme> From<Clock<Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Theme: 'a + StyleSheet,
+{
+    fn from(clock: Clock<Theme>) -> Self {
+        Element::new(clock)
+    }
+}
@@ -0,0 +1,653 @@
+//! Display a text input with a searchable dropdown list of options.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+use std::{borrow::Cow, cell::RefCell, fmt::Display, hash::Hash, marker::PhantomData, rc::Rc};
+
+use iced_widget::{
+    core::{
+        self, event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+    },
+    runtime::Font,
+    scrollable::{self, AbsoluteOffset},
+    text::LineHeight,
+    text_input::{self, Value},
+    Scrollable, TextInput,
+};
+
+use crate::{
+    core::search::SearchMode,
+    native::{
+        overlay::ComboBoxOverlay,
+        selection_list::list::{Entry, List, ListState},
+    },
+    style::selection_list::StyleSheet,
+};
+
+/// The default maximum height of the dropdown list.
+const DEFAULT_MAX_HEIGHT: f32 = 200.0;
+
+/// A callback producing a message for the selected index and option.
+type OnSelected<'a, T, Message> = Box<dyn Fn(usize, T) -> Message + 'a>;
+/// A callback producing a message for the typed text.
+type OnInput<'a, Message> = Box<dyn Fn(String) -> Message + 'a>;
+
+/// A text input showing a dropdown list of options below it while focused.
+///
+/// Typing filters the options, the arrow and page keys move through them and
+/// enter selects the hovered option. The text of the field is owned by the
+/// application: usually it is set to the label of the selected option.
+///
+/// By default the typed text is only used to filter the options, the best
+/// match is hovered and the typed text reverts to the text of the field once
+/// the dropdown closes. With [`on_input`](Self::on_input) any text can be
+/// entered and enter submits it, unless an option was hovered with the arrow
+/// keys or the mouse.
+///
+/// The dropdown uses the style of a [`SelectionList`](crate::SelectionList).
+///
+/// `iced` has a `ComboBox` widget as well, so glob imports of both
+/// `iced::widget::*` and `iced_aw::*` make the name ambiguous. Import this one
+/// explicitly, e.g. as `use iced_aw::ComboBox;`, or refer to it by its path.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::ComboBox;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LanguageSelected(usize, String),
+/// }
+///
+/// let languages = vec![String::from("Rust"), String::from("Elm")];
+///
+/// let combo_box = ComboBox::new(
+///     &languages,
+///     "Pick a language...",
+///     "Rust",
+///     Message::LanguageSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer = crate::Renderer>
+where
+    T: Clone + Display + Eq + Hash,
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The options of the dropdown list.
+    options: Rc<Cow<'a, [T]>>,
+    /// The text of the field while no text is typed.
+    text: String,
+    /// The text input of the field.
+    content: TextInput<'a, InternalMessage, Renderer>,
+    /// The message to produce when an option is selected.
+    on_selected: OnSelected<'a, T, Message>,
+    /// The message to produce when text is typed.
+    on_input: Option<OnInput<'a, Message>>,
+    /// How the typed text is matched against the options.
+    search_mode: SearchMode,
+    /// The text size of the field and its options.
+    text_size: f32,
+    /// The padding of the field and its options.
+    padding: f32,
+    /// The font of the field and its options.
+    font: Renderer::Font,
+    /// The maximum height of the dropdown list.
+    max_height: f32,
+    /// The style of the dropdown list.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + Display + Eq + Hash,
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`ComboBox`] with the given owned or borrowed `options`, the `placeholder`
+    /// shown while the field is empty, the `text` of the field and the message
+    /// to produce when an option is selected.
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        placeholder: &str,
+        text: &str,
+        on_selected: impl Fn(usize, T) -> Message + 'a,
+    ) -> Self {
+        let text_size = 12.0;
+        let padding = 5.0;
+
+        Self {
+            options: Rc::new(options.into()),
+            text: text.to_owned(),
+            content: TextInput::new(placeholder, text)
+                .on_input(InternalMessage::Input)
+                .on_submit(InternalMessage::Submit)
+                .size(text_size)
+                .padding(padding),
+            on_selected: Box::new(on_selected),
+            on_input: None,
+            search_mode: SearchMode::default(),
+            text_size,
+            padding,
+            font: Font::default(),
+            max_height: DEFAULT_MAX_HEIGHT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Allows entering any text into the [`ComboBox`], not just the labels of
+    /// its options.
+    ///
+    /// The given function produces a message with the text whenever it is
+    /// changed, so the application can store it as the new text of the field.
+    #[must_use]
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets how the typed text is matched against the options of the
+    /// [`ComboBox`]. Defaults to [`SearchMode::Substring`].
+    #[must_use]
+    pub fn search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_mode = search_mode;
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.content = self.content.width(width);
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`] and its options.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self.content = self.content.size(text_size);
+        self
+    }
+
+    /// Sets the padding of the [`ComboBox`] and its options.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self.content = self.content.padding(padding);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`] and its options.
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self.content = self.content.font(font);
+        self
+    }
+
+    /// Sets the maximum height of the dropdown list of the [`ComboBox`].
+    #[must_use]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the style of the dropdown list of the [`ComboBox`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the text shown in the field.
+    fn value(&self, state: &State) -> Value {
+        Value::new(state.query.as_deref().unwrap_or(&self.text))
+    }
+
+    /// Returns the rows of the options matching the typed text, or `None` if
+    /// all options are shown.
+    fn rows(&self, state: &State) -> Option<Vec<Entry>> {
+        let query = state.query.as_deref().filter(|query| !query.is_empty())?;
+
+        Some(
+            self.options
+                .iter()
+                .enumerate()
+                .filter_map(|(index, option)| {
+                    self.search_mode
+                        .find(&option.to_string(), query)
+                        .map(|matched| Entry::Option { index, matched })
+                })
+                .collect(),
+        )
+    }
+
+    /// Builds the dropdown list showing the given rows.
+    fn dropdown(
+        &self,
+        rows: Option<Vec<Entry>>,
+        selected: Option<usize>,
+    ) -> Element<'a, InternalMessage, Renderer>
+    where
+        Renderer: 'a,
+        Renderer::Theme: scrollable::StyleSheet,
+    {
+        Scrollable::new(self.list(rows, selected)).into()
+    }
+
+    /// The list of the dropdown.
+    fn list(
+        &self,
+        rows: Option<Vec<Entry>>,
+        selected: Option<usize>,
+    ) -> List<'a, T, InternalMessage, Renderer> {
+        List {
+            options: Rc::clone(&self.options),
+            font: self.font,
+            style: self.style.clone(),
+            on_selected: Rc::new(|index, _| InternalMessage::Selected(index)),
+            on_selection_change: None,
+            padding: self.padding,
+            text_size: self.text_size,
+            selected,
+            selection: None,
+            rows,
+            headers: Vec::new(),
+            row_builder: None,
+            contents: RefCell::default(),
+            on_reorder: None,
+            key: None,
+            phantomdata: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for ComboBox<'a, T, Message, Renderer>
+where
+    T: 'a + Clone + Display + Eq + Hash,
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet + scrollable::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.content as &dyn Widget<_, _>),
+            Tree::new(self.dropdown(None, None)),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.content as &dyn Widget<_, _>);
+    }
+
+    fn width(&self) -> Length {
+        Widget::<InternalMessage, Renderer>::width(&self.content)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        Widget::<InternalMessage, Renderer>::layout(&self.content, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(
+            tree.children[0].state.downcast_mut::<text_input::State>(),
+            None,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let mut value = self.value(tree.state.downcast_ref::<State>());
+        let [input, dropdown] = &mut tree.children[..] else {
+            return event::Status::Ignored;
+        };
+        let state = tree.state.downcast_mut::<State>();
+        let was_focused = input.state.downcast_ref::<text_input::State>().is_focused();
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
+            match key_code {
+                keyboard::KeyCode::Escape if state.is_open => {
+                    state.close();
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                    if was_focused && !state.is_open =>
+                {
+                    state.is_open = true;
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let status = text_input::update(
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &mut value,
+            Some(self.text_size),
+            LineHeight::default(),
+            Some(self.font),
+            false,
+            Some(&InternalMessage::Input),
+            None,
+            &Some(InternalMessage::Submit),
+            || input.state.downcast_mut::<text_input::State>(),
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        for message in messages {
+            match message {
+                InternalMessage::Input(text) => {
+                    if let Some(on_input) = &self.on_input {
+                        shell.publish(on_input(text.clone()));
+                    }
+                    let hovered_option = state.input(text, self.on_input.is_some());
+
+                    // Show the list from its start.
+                    let list_state = dropdown.children[0].state.downcast_mut::<ListState>();
+                    list_state.hovered_option = hovered_option;
+                    list_state.hovered_key = None;
+                    dropdown
+                        .state
+                        .downcast_mut::<scrollable::State>()
+                        .scroll_to(AbsoluteOffset { x: 0.0, y: 0.0 });
+                }
+                InternalMessage::Submit => {
+                    if self.on_input.is_some() {
+                        state.close();
+                    }
+                }
+                InternalMessage::Selected(_) => {}
+            }
+        }
+
+        match (
+            was_focused,
+            input.state.downcast_ref::<text_input::State>().is_focused(),
+        ) {
+            (false, true) => {
+                state.is_open = true;
+                // An option hovered the last time the list was open must not
+                // replace the free text on enter.
+                if self.on_input.is_some() {
+                    let list_state = dropdown.children[0].state.downcast_mut::<ListState>();
+                    list_state.hovered_option = None;
+                    list_state.hovered_key = None;
+                }
+            }
+            (true, false) => state.close(),
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        text_input::mouse_interaction(layout, cursor, false)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        self.content.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            Some(&self.value(tree.state.downcast_ref::<State>())),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        if !tree.state.downcast_ref::<State>().is_open {
+            return None;
+        }
+
+        let rows = self.rows(tree.state.downcast_ref::<State>());
+        let selected = self
+            .options
+            .iter()
+            .position(|option| option.to_string() == self.text);
+        let dropdown = self.dropdown(rows, selected);
+
+        let [_, dropdown_tree] = &mut tree.children[..] else {
+            return None;
+        };
+        dropdown.as_widget().diff(dropdown_tree);
+        sync_list_state(
+            dropdown_tree.children[0].state.downcast_mut::<ListState>(),
+            selected,
+        );
+
+        Some(
+            ComboBoxOverlay::new(
+                dropdown_tree,
+                dropdown,
+                tree.state.downcast_mut::<State>(),
+                &self.options,
+                &self.on_selected,
+                layout.bounds(),
+                self.max_height,
+                self.style.clone(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + Display + Eq + Hash,
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet + scrollable::StyleSheet,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Element::new(combo_box)
+    }
+}
+
+/// Prepares the state of the dropdown list for the keyboard navigation of
+/// the field.
+fn sync_list_state(list_state: &mut ListState, selected: Option<usize>) {
+    // The list is navigated with the keyboard while the field is focused.
+    list_state.is_focused = true;
+    // A text that matches no option is submitted on enter, instead of the
+    // option an earlier text selected.
+    if selected.is_none() {
+        list_state.last_selected_index = None;
+        list_state.anchor = None;
+    }
+}
+
+/// The state of a [`ComboBox`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether the dropdown list is shown.
+    pub is_open: bool,
+    /// The text typed since the dropdown list was opened.
+    pub query: Option<String>,
+}
+
+impl State {
+    /// Opens the dropdown list filtered by the typed text and returns the row
+    /// to hover: the best match if only options can be picked, or none if any
+    /// text can be entered, so that enter submits the text instead.
+    pub fn input(&mut self, text: String, is_free_text: bool) -> Option<usize> {
+        self.query = Some(text);
+        self.is_open = true;
+
+        (!is_free_text).then_some(0)
+    }
+
+    /// Closes the dropdown list and drops the typed text.
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.query = None;
+    }
+}
+
+/// The messages of the underlying text input and list of a [`ComboBox`].
+#[derive(Clone, Debug)]
+pub(crate) enum InternalMessage {
+    /// The text of the text input changed.
+    Input(String),
+    /// The enter key was pressed without a hovered option.
+    Submit,
+    /// The option at the index was selected.
+    Selected(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::{event, keyboard, Rectangle, Shell};
+
+    use super::{sync_list_state, ComboBox, State};
+    use crate::native::selection_list::list::ListState;
+
+    #[test]
+    fn input_test() {
+        let mut state = State::default();
+        assert_eq!(state.input(String::from("Ru"), false), Some(0));
+        assert!(state.is_open);
+        assert_eq!(state.query.as_deref(), Some("Ru"));
+
+        // Free text is not replaced by the first partial match on enter.
+        let mut state = State::default();
+        assert_eq!(state.input(String::from("Ru"), true), None);
+        assert!(state.is_open);
+        assert_eq!(state.query.as_deref(), Some("Ru"));
+
+        state.close();
+        assert!(!state.is_open);
+        assert_eq!(state.query, None);
+    }
+
+    #[test]
+    fn owned_options_test() {
+        // Generated options do not need to outlive the view.
+        let combo_box: ComboBox<'_, String, (usize, String)> = ComboBox::new(
+            (1..=3).map(|i| format!("Option {i}")).collect::<Vec<_>>(),
+            "Pick an option...",
+            "",
+            |index, option| (index, option),
+        );
+        assert_eq!(combo_box.options.len(), 3);
+
+        let borrowed = [String::from("Rust")];
+        let combo_box: ComboBox<'_, String, (usize, String)> =
+            ComboBox::new(&borrowed[..], "", "Rust", |index, option| (index, option));
+        assert_eq!(combo_box.options.len(), 1);
+    }
+
+    #[test]
+    fn enter_submits_free_text_test() {
+        let combo_box: ComboBox<'_, String, (usize, String)> = ComboBox::new(
+            vec![String::from("Rust"), String::from("Elm")],
+            "",
+            "Ru",
+            |index, option| (index, option),
+        );
+        let list = combo_box.list(None, None);
+        // "Rust" was selected before the text was changed to "Ru".
+        let mut list_state = ListState {
+            last_selected_index: Some((0, 0)),
+            anchor: Some((0, 0)),
+            ..ListState::default()
+        };
+        sync_list_state(&mut list_state, None);
+
+        let bounds = Rectangle::new([0.0, 0.0].into(), [100.0, 100.0].into());
+        let mut messages = Vec::new();
+        let status = list.navigate(
+            &mut list_state,
+            keyboard::KeyCode::Enter,
+            bounds,
+            &bounds,
+            &mut Shell::new(&mut messages),
+        );
+        assert_eq!(status, event::Status::Ignored);
+        assert!(messages.is_empty());
+
+        // A hovered option is still selected on enter.
+        list_state.hovered_option = Some(1);
+        let status = list.navigate(
+            &mut list_state,
+            keyboard::KeyCode::Enter,
+            bounds,
+            &bounds,
+            &mut Shell::new(&mut messages),
+        );
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages.len(), 1);
+    }
+}
@@ -2,7 +2,6 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
-use chrono::Local;
 use iced_widget::{
     button, container,
     core::{
@@ -20,7 +19,7 @@ use iced_widget::{
     text,
 };
 
-pub use crate::core::date::Date;
+pub use crate::core::date::{Date, ParseDateError};
 
 pub use crate::style::date_picker::{Appearance, StyleSheet};
 
@@ -141,13 +140,13 @@ impl State {
     #[must_use]
     pub fn new(date: Date) -> Self {
         Self {
-            overlay_state: date_picker::State::new(date.into()),
+            overlay_state: date_picker::State::new(date),
         }
     }
 
     /// Resets the date of the state to the current date.
     pub fn reset(&mut self) {
-        self.overlay_state.date = Local::now().naive_local().date();
+        self.overlay_state.date = Date::today();
     }
 }
 
@@ -273,13 +273,7 @@ where
         + iced_widget::container::StyleSheet
         + iced_widget::text::StyleSheet,
     F: 'static + Fn(T) -> Message + Copy,
-    T: 'static
-        + num_traits::Num
-        + num_traits::NumAssignOps
-        + PartialOrd
-        + std::fmt::Display
-        + std::str::FromStr
-        + Copy,
+    T: 'static + crate::native::number_input::Number,
 {
     crate::NumberInput::new(value, max, on_changed)
 }
@@ -290,7 +284,7 @@ where
 /// [`SelectionList`]: crate::SelectionList
 #[must_use]
 pub fn selection_list_with<'a, T, Message, Renderer>(
-    options: &'a [T],
+    options: impl Into<Cow<'a, [T]>>,
     on_selected: impl Fn(usize, T) -> Message + 'static,
     text_size: f32,
     padding: f32,
@@ -303,7 +297,8 @@ where
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: crate::style::selection_list::StyleSheet
         + iced_widget::container::StyleSheet
-        + iced_widget::scrollable::StyleSheet,
+        + iced_widget::scrollable::StyleSheet
+        + iced_widget::text_input::StyleSheet,
     T: Clone + Display + Eq + Hash,
     [T]: ToOwned<Owned = Vec<T>>,
 {
@@ -324,7 +319,7 @@ where
 /// [`SelectionList`]: crate::SelectionList
 #[must_use]
 pub fn selection_list<'a, T, Message, Renderer>(
-    options: &'a [T],
+    options: impl Into<Cow<'a, [T]>>,
     on_selected: impl Fn(usize, T) -> Message + 'static,
 ) -> crate::SelectionList<'a, T, Message, Renderer>
 where
@@ -332,7 +327,8 @@ where
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: crate::style::selection_list::StyleSheet
         + iced_widget::container::StyleSheet
-        + iced_widget::scrollable::StyleSheet,
+        + iced_widget::scrollable::StyleSheet
+        + iced_widget::text_input::StyleSheet,
     T: Clone + Display + Eq + Hash,
     [T]: ToOwned<Owned = Vec<T>>,
 {
@@ -24,6 +24,11 @@ pub mod card;
 /// A card consisting of a head, body and optional foot.
 pub type Card<'a, Message, Renderer> = card::Card<'a, Message, Renderer>;
 
+#[cfg(feature = "clock")]
+pub mod clock;
+#[cfg(feature = "clock")]
+pub use clock::Clock;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
@@ -49,6 +54,12 @@ pub type SelectionList<'a, T, Message, Renderer> =
 #[cfg(feature = "selection_list")]
 pub use selection_list::List;
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+/// A text input with a searchable dropdown list of options.
+pub type ComboBox<'a, T, Message, Renderer> = combo_box::ComboBox<'a, T, Message, Renderer>;
+
 #[cfg(feature = "floating_element")]
 pub mod floating_element;
 #[cfg(feature = "floating_element")]
@@ -80,7 +91,7 @@ pub type Split<'a, Message, Renderer> = split::Split<'a, Message, Renderer>;
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
 /// A tab bar to show tabs.
-pub type TabBar<Message, TabId, Renderer> = tab_bar::TabBar<Message, TabId, Renderer>;
+pub type TabBar<'a, Message, TabId, Renderer> = tab_bar::TabBar<'a, Message, TabId, Renderer>;
 
 #[cfg(feature = "tab_bar")]
 pub use tab_bar::TabLabel;
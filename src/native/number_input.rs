@@ -9,33 +9,69 @@ use iced_widget::{
         event, keyboard,
         layout::{Limits, Node},
         mouse::{self, Cursor},
-        renderer,
+        renderer, touch,
         widget::{
             tree::{State, Tag},
             Operation, Tree,
         },
-        Alignment, Background, Clipboard, Color, Element, Event, Layout, Length, Padding,
-        Rectangle, Shell, Size, Widget,
+        window, Alignment, Background, Clipboard, Color, Element, Event, Layout, Length, Padding,
+        Point, Rectangle, Shell, Size, Widget,
     },
     style, text,
     text::LineHeight,
-    text_input::{self, cursor, Value},
+    text_input::{self, Value},
     Column, Container, Row, Text, TextInput,
 };
 
-use num_traits::{Num, NumAssignOps};
-use std::{fmt::Display, str::FromStr};
+use std::time::{Duration, Instant};
+
+use crate::core::number::{self, group_digits};
 
 pub use crate::{
+    core::number::{Number, Radix},
     graphics::icons::{Icon, ICON_FONT},
     style::number_input::{self, Appearance, StyleSheet},
 };
 
 /// The default padding
 const DEFAULT_PADDING: f32 = 5.0;
+/// The scrolled pixels of a touchpad which result in one step.
+const SCROLL_PIXELS_PER_STEP: f32 = 20.0;
+/// The horizontally dragged pixels which result in one step.
+const SCRUB_PIXELS_PER_STEP: f32 = 5.0;
+/// The distance the cursor needs to be dragged before the value is scrubbed.
+const SCRUB_THRESHOLD: f32 = 3.0;
+/// The size of the error message relative to the text size.
+const MESSAGE_SIZE_FACTOR: f32 = 0.8;
+/// The space between the field and the error message.
+const MESSAGE_SPACING: f32 = 2.0;
+/// The delay before a held modifier button starts repeating.
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+/// A function validating the values of a [`NumberInput`].
+type Validator<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
+/// Calculates the interval until the next repeat of a held modifier button,
+/// accelerating with the number of repeats done so far.
+fn repeat_interval(repeats: u32) -> Duration {
+    let interval = 150.0 * 0.85_f32.powi(repeats.min(30) as i32);
+    Duration::from_secs_f32(interval.max(20.0) / 1000.0)
+}
 
 /// A field that can only be filled with numeric type.
 ///
+/// Besides typing, the value can be stepped with the modifier buttons (hold
+/// them to repeat), the arrow keys, the mouse wheel or by dragging the cursor
+/// horizontally over the unfocused field. Holding shift steps ten times the
+/// step, holding control a tenth of it.
+///
+/// Arithmetic expressions like `12*4+3`, `1920/2` or `=100-15%` can be typed as
+/// well. They are evaluated when the enter key is pressed or the field loses
+/// the focus.
+///
+/// The value can be of any type implementing [`Number`], which includes all
+/// primitive numbers.
+///
 /// # Example
 /// ```ignore
 /// # use iced_aw::NumberInput;
@@ -75,9 +111,27 @@ where
     /// The text size of the [`NumberInput`].
     size: Option<f32>,
     /// The underlying element of the [`NumberInput`].
-    content: TextInput<'a, Message, Renderer>,
+    content: TextInput<'a, InternalMessage, Renderer>,
     /// The on_change event of the [`NumberInput`].
     on_change: Box<dyn Fn(T) -> Message>,
+    /// The message that is produced when the enter key is pressed.
+    on_submit: Option<Message>,
+    /// The character separating the integer and the fractional part.
+    decimal_separator: char,
+    /// The number of decimal places shown.
+    precision: Option<usize>,
+    /// The character grouping the digits of the integer part into thousands.
+    group_separator: Option<char>,
+    /// The text shown in front of the value.
+    prefix: String,
+    /// The text shown behind the value.
+    suffix: String,
+    /// The radix an integer value is shown and typed in.
+    radix: Radix,
+    /// The function validating the values of the [`NumberInput`].
+    validate: Option<Validator<T>>,
+    /// Whether error messages are shown under the field.
+    show_messages: bool,
     /// The style of the [`NumberInput`].
     style: <Renderer::Theme as number_input::StyleSheet>::Style,
     /// The font text of the [`NumberInput`].
@@ -88,7 +142,7 @@ where
 
 impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
 where
-    T: Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    T: Number,
     Message: Clone,
     Renderer: core::text::Renderer<Font = core::Font>,
     Renderer::Theme: number_input::StyleSheet
@@ -109,9 +163,6 @@ where
         T: 'static,
     {
         let padding = DEFAULT_PADDING;
-        let convert_to_num = move |s: String| {
-            on_changed(T::from_str(&s).unwrap_or(if s.is_empty() { T::zero() } else { value }))
-        };
 
         Self {
             value,
@@ -119,11 +170,20 @@ where
             bounds: (T::zero(), max),
             padding,
             size: None,
-            content: TextInput::new("", format!("{value}").as_str())
-                .on_input(convert_to_num)
+            content: TextInput::new("", "")
+                .on_input(InternalMessage::Input)
                 .padding(padding)
                 .width(Length::Fixed(127.0)),
             on_change: Box::new(on_changed),
+            on_submit: None,
+            decimal_separator: '.',
+            precision: None,
+            group_separator: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            radix: Radix::Decimal,
+            validate: None,
+            show_messages: false,
             style: <Renderer::Theme as number_input::StyleSheet>::Style::default(),
             font: Renderer::Font::default(),
             width: Length::Fill,
@@ -208,9 +268,93 @@ where
 
     /// Sets the message that should be produced when the [`NumberInput`] is
     /// focused and the enter key is pressed.
+    ///
+    /// The typed value or expression is evaluated and clamped to the bounds
+    /// before the message is produced. No message is produced if the typed
+    /// expression is invalid.
     #[must_use]
     pub fn on_submit(mut self, message: Message) -> Self {
-        self.content = self.content.on_submit(message);
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the character separating the integer and the fractional part of
+    /// the [`NumberInput`], e.g. `','` for many european locales.
+    ///
+    /// The default is `'.'`.
+    #[must_use]
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the number of decimal places the value of the [`NumberInput`] is
+    /// shown with while it is not focused.
+    ///
+    /// The value itself is not rounded and is edited with all its digits.
+    /// Without a precision, float values are
+    /// shown with their significant digits only and stepping rounds them to
+    /// the decimal places of the step, so `0.1 + 0.2` is shown as `0.3`.
+    #[must_use]
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Groups the digits of the integer part into thousands using the given
+    /// separator while the [`NumberInput`] is not focused.
+    #[must_use]
+    pub fn group_separator(mut self, separator: char) -> Self {
+        self.group_separator = Some(separator);
+        self
+    }
+
+    /// Sets the text shown in front of the value while the [`NumberInput`] is
+    /// not focused, e.g. a currency symbol.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the text shown behind the value while the [`NumberInput`] is not
+    /// focused, e.g. a unit like `"px"` or `"%"`.
+    #[must_use]
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Sets the radix the value of an integer [`NumberInput`] is shown and
+    /// typed in, e.g. [`Radix::Hexadecimal`] for `0xFF`.
+    ///
+    /// The prefix of the radix is optional while typing. Fractional values are
+    /// always shown in [`Radix::Decimal`].
+    #[must_use]
+    pub fn radix(mut self, radix: Radix) -> Self {
+        self.radix = radix;
+        self
+    }
+
+    /// Sets the function validating the values of the [`NumberInput`].
+    ///
+    /// Typed values failing the validation are not published. The returned
+    /// message is shown with the invalid appearance, see
+    /// [`show_messages`](Self::show_messages).
+    #[must_use]
+    pub fn validate<F>(mut self, validate: F) -> Self
+    where
+        F: 'static + Fn(&T) -> Result<(), String>,
+    {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Shows error messages under the field of the [`NumberInput`], e.g. why a
+    /// typed value is rejected. Space for one line is reserved for them.
+    #[must_use]
+    pub fn show_messages(mut self, show_messages: bool) -> Self {
+        self.show_messages = show_messages;
         self
     }
 
@@ -234,36 +378,302 @@ where
         self
     }
 
-    /// Decrease current value by step of the [`NumberInput`].
-    fn decrease_val(&mut self, shell: &mut Shell<Message>) {
+    /// Rounds a value stepped by the given step to the decimal places of the
+    /// step and of the current value, so that floats like `0.1 + 0.2` end up
+    /// at `0.3`.
+    fn round_step(&self, value: T, step: &T) -> T {
+        if !T::is_fractional() {
+            return value;
+        }
+
+        let decimals = number::decimal_places(&step.format(None))
+            .max(number::decimal_places(&self.value.format(None)));
+        T::parse(&value.format(Some(decimals))).unwrap_or(value)
+    }
+
+    /// Decrease current value by the given step of the [`NumberInput`].
+    fn decrease_val(&mut self, step: &T, shell: &mut Shell<Message>) {
         if self.value > self.bounds.0 {
-            let new_val = self.value - self.step;
-            self.value = if new_val > self.bounds.0 {
-                new_val
+            self.value = if &self.value.sub(&self.bounds.0) > step {
+                self.round_step(self.value.sub(step), step)
             } else {
-                self.bounds.0
+                self.bounds.0.clone()
             };
-            shell.publish((self.on_change)(self.value));
+            shell.publish((self.on_change)(self.value.clone()));
         }
     }
 
-    /// Increase current value by step of the [`NumberInput`].
-    fn increase_val(&mut self, shell: &mut Shell<Message>) {
+    /// Increase current value by the given step of the [`NumberInput`].
+    fn increase_val(&mut self, step: &T, shell: &mut Shell<Message>) {
         if self.value < self.bounds.1 {
-            let new_val = self.value + self.step;
-            self.value = if new_val < self.bounds.1 {
-                new_val
+            self.value = if &self.bounds.1.sub(&self.value) > step {
+                self.round_step(self.value.add(step), step)
             } else {
-                self.bounds.1
+                self.bounds.1.clone()
             };
-            shell.publish((self.on_change)(self.value));
+            shell.publish((self.on_change)(self.value.clone()));
+        }
+    }
+
+    /// Returns the step of the [`NumberInput`] scaled by the pressed keyboard
+    /// modifiers: shift for ten times the step, control for a tenth of it.
+    fn scaled_step(&self, modifiers: keyboard::Modifiers) -> T {
+        if modifiers.shift() {
+            self.step.times_ten()
+        } else if modifiers.control() {
+            let fine = self.step.tenth();
+            if fine == T::zero() {
+                self.step.clone()
+            } else {
+                fine
+            }
+        } else {
+            self.step.clone()
         }
     }
+
+    /// Steps the value by the whole steps in the accumulated amount and keeps
+    /// the remainder for the next time.
+    fn accumulate(
+        &mut self,
+        accumulator: &mut f32,
+        amount: f32,
+        step: &T,
+        shell: &mut Shell<Message>,
+    ) {
+        *accumulator += amount;
+        let steps = accumulator.trunc();
+        *accumulator -= steps;
+
+        for _ in 0..steps.abs() as u32 {
+            if steps > 0.0 {
+                self.increase_val(step, shell);
+            } else {
+                self.decrease_val(step, shell);
+            }
+        }
+    }
+
+    /// Returns the radix of the [`NumberInput`] if it is not decimal and
+    /// supported by its type.
+    fn integer_radix(&self) -> Option<Radix> {
+        (self.radix != Radix::Decimal && self.value.format_radix(self.radix).is_some())
+            .then_some(self.radix)
+    }
+
+    /// Formats the value as it is edited in the [`NumberInput`].
+    ///
+    /// The text is exact, so that leaving the [`NumberInput`] without editing
+    /// it keeps the value.
+    fn format(&self, value: &T) -> String {
+        self.integer_radix()
+            .and_then(|radix| value.format_radix(radix))
+            .unwrap_or_else(|| self.localize(value.format_exact()))
+    }
+
+    /// Replaces the `.` of a formatted number by the decimal separator of the
+    /// [`NumberInput`].
+    fn localize(&self, text: String) -> String {
+        if self.decimal_separator == '.' {
+            text
+        } else {
+            text.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+
+    /// Parses the text of the [`NumberInput`] into a value.
+    fn parse(&self, text: &str) -> Option<T> {
+        if let Some(radix) = self.integer_radix() {
+            return T::parse_radix(text, radix);
+        }
+
+        T::parse(&text.replace(self.decimal_separator, "."))
+    }
+
+    /// Checks if the character may be part of a number or an arithmetic
+    /// expression typed into the [`NumberInput`].
+    fn is_accepted_char(&self, c: char) -> bool {
+        if self.integer_radix().is_some() {
+            return c.is_ascii_hexdigit() || matches!(c, '-' | 'x' | 'X' | 'o' | 'O');
+        }
+
+        c.is_ascii_digit() || c == self.decimal_separator || "+-*/%()= ".contains(c)
+    }
+
+    /// Evaluates the text of the [`NumberInput`], which is either a number or
+    /// an arithmetic expression like `12*4+3`, into a value within the bounds.
+    fn evaluate(&self, text: &str) -> Option<T> {
+        if let Some(value) = self.parse(text) {
+            return Some(self.clamp(value));
+        }
+
+        if self.integer_radix().is_some() {
+            return None;
+        }
+
+        let result = number::evaluate(&text.replace(self.decimal_separator, "."))?;
+
+        // Clamp before the conversion, as e.g. negative results do not fit
+        // into unsigned types.
+        let result = match (self.bounds.0.to_f64(), self.bounds.1.to_f64()) {
+            (Some(min), Some(max)) => result.clamp(min, max),
+            _ => result,
+        };
+
+        T::from_f64(result).map(|value| self.clamp(value))
+    }
+
+    /// Clamps the value to the bounds of the [`NumberInput`].
+    fn clamp(&self, value: T) -> T {
+        if value < self.bounds.0 {
+            self.bounds.0.clone()
+        } else if value > self.bounds.1 {
+            self.bounds.1.clone()
+        } else {
+            value
+        }
+    }
+
+    /// Formats the value as it is shown in the [`NumberInput`] while it is not
+    /// focused.
+    fn display(&self, value: &T) -> String {
+        let text = self
+            .integer_radix()
+            .and_then(|radix| value.format_radix(radix))
+            .unwrap_or_else(|| self.localize(value.format(self.precision)));
+        let text = match self.group_separator {
+            Some(separator) if self.integer_radix().is_none() => {
+                group_digits(&text, self.decimal_separator, separator)
+            }
+            _ => text,
+        };
+
+        format!("{}{text}{}", self.prefix, self.suffix)
+    }
+
+    /// Returns the text that is currently shown in the [`NumberInput`].
+    fn text(&self, state: &NumberInputState, is_focused: bool) -> String {
+        if !is_focused {
+            return self.display(&self.value);
+        }
+
+        let formatted = self.format(&self.value);
+
+        match &state.text {
+            Some(text) if state.synced == formatted => text.clone(),
+            _ => formatted,
+        }
+    }
+
+    /// Applies the text typed into the [`NumberInput`].
+    ///
+    /// Values within the bounds are published right away, everything else is
+    /// kept as text until the [`NumberInput`] is submitted or loses the focus.
+    ///
+    /// Rejected characters, values out of bounds and values failing the
+    /// validation are reported by the error of the state.
+    fn input(&mut self, state: &mut NumberInputState, text: String, shell: &mut Shell<Message>) {
+        if let Some(c) = text.chars().find(|&c| !self.is_accepted_char(c)) {
+            state.error = Some(format!("'{c}' is not allowed"));
+            return;
+        }
+
+        state.error = None;
+
+        if let Some(value) = self.parse(&text) {
+            if value < self.bounds.0 || value > self.bounds.1 {
+                state.error = Some(format!(
+                    "The value must be between {} and {}",
+                    self.format(&self.bounds.0),
+                    self.format(&self.bounds.1)
+                ));
+            } else if let Err(error) = self.check(&value) {
+                state.error = Some(error);
+            } else {
+                state.synced = self.format(&value);
+                if value != self.value {
+                    self.value = value.clone();
+                    shell.publish((self.on_change)(value));
+                }
+            }
+        }
+
+        state.text = Some(text);
+    }
+
+    /// Validates the value with the validation function of the [`NumberInput`].
+    fn check(&self, value: &T) -> Result<(), String> {
+        self.validate
+            .as_ref()
+            .map_or(Ok(()), |validate| validate(value))
+    }
+
+    /// Returns the error of the [`NumberInput`] to show, if any.
+    ///
+    /// Errors of the typed text are only shown while the text is.
+    fn error(&self, state: &NumberInputState, is_focused: bool) -> Option<String> {
+        state
+            .error
+            .clone()
+            .filter(|_| is_focused && state.synced == self.format(&self.value))
+            .or_else(|| self.check(&self.value).err())
+    }
+
+    /// Fills the text of the [`NumberInput`] with its value once it gains the
+    /// focus.
+    fn focus(&self, state: &mut NumberInputState) {
+        state.synced = self.format(&self.value);
+        state.text = Some(state.synced.clone());
+    }
+
+    /// Commits the text of the [`NumberInput`] once it loses the focus.
+    fn blur(&mut self, state: &mut NumberInputState, shell: &mut Shell<Message>) {
+        // Invalid expressions are dropped in favor of the current value.
+        if self.commit(state, shell).is_err() {
+            state.error = None;
+        }
+        state.text = None;
+    }
+
+    /// Commits the typed text of the [`NumberInput`] by evaluating it and
+    /// clamping the result to the bounds.
+    ///
+    /// Returns an error if the text is neither a number nor a valid expression
+    /// or if the value fails the validation.
+    fn commit(
+        &mut self,
+        state: &mut NumberInputState,
+        shell: &mut Shell<Message>,
+    ) -> Result<(), String> {
+        // Unedited text belongs to the current value.
+        let value = match state.text.as_deref() {
+            Some(text) if text != state.synced => self.evaluate(text),
+            _ => Some(self.value.clone()),
+        }
+        .ok_or_else(|| {
+            if self.integer_radix().is_some() {
+                String::from("Invalid number")
+            } else {
+                String::from("Invalid number or expression")
+            }
+        })?;
+        self.check(&value)?;
+
+        state.synced = self.format(&value);
+        if value != self.value {
+            self.value = value.clone();
+            shell.publish((self.on_change)(value));
+        }
+
+        state.text = Some(state.synced.clone());
+        state.error = None;
+        Ok(())
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for NumberInput<'a, T, Message, Renderer>
 where
-    T: Num + NumAssignOps + PartialOrd + Display + FromStr + ToString + Copy,
+    T: Number,
     Message: 'a + Clone,
     Renderer: 'a + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: number_input::StyleSheet
@@ -272,10 +682,10 @@ where
         + text::StyleSheet,
 {
     fn tag(&self) -> Tag {
-        Tag::of::<ModifierState>()
+        Tag::of::<NumberInputState>()
     }
     fn state(&self) -> State {
-        State::new(ModifierState::default())
+        State::new(NumberInputState::default())
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -337,33 +747,39 @@ where
                 .push(btn_mod('▼'))
                 .layout(renderer, &limits2.loose())
         };
-        let intrinsic = Size::new(
+        let mut intrinsic = Size::new(
             content.size().width - 1.0,
             content.size().height.max(modifier.size().height),
         );
         modifier.align(Alignment::End, Alignment::Center, intrinsic);
 
+        let mut children = vec![content, modifier];
+        if self.show_messages {
+            let message_height = LineHeight::default()
+                .to_absolute((txt_size * MESSAGE_SIZE_FACTOR).into())
+                .0;
+            let mut message = Node::new(Size::new(intrinsic.width, message_height));
+            message.move_to(Point::new(0.0, intrinsic.height + MESSAGE_SPACING));
+            intrinsic.height += MESSAGE_SPACING + message_height;
+            children.push(message);
+        }
+
         let size = limits.resolve(intrinsic);
-        Node::with_children(size, vec![content, modifier])
+        Node::with_children(size, children)
     }
 
     fn operate(
         &self,
         tree: &mut Tree,
         layout: Layout<'_>,
-        renderer: &Renderer,
+        _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         operation.container(None, layout.bounds(), &mut |operation| {
-            self.content.operate(
-                &mut tree.children[0],
-                layout
-                    .children()
-                    .next()
-                    .expect("NumberInput inner child Textbox was not created."),
-                renderer,
-                operation,
-            );
+            let state = tree.children[0].state.downcast_mut::<text_input::State>();
+
+            operation.focusable(state, None);
+            operation.text_input(state, None);
         });
     }
 
@@ -377,7 +793,7 @@ where
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
-        viewport: &Rectangle,
+        _viewport: &Rectangle,
     ) -> event::Status {
         let mut children = layout.children();
         let content = children.next().expect("fail to get content layout");
@@ -395,216 +811,194 @@ where
             .bounds();
         let mouse_over_inc = inc_bounds.contains(cursor.position().unwrap_or_default());
         let mouse_over_dec = dec_bounds.contains(cursor.position().unwrap_or_default());
-        let modifiers = state.state.downcast_mut::<ModifierState>();
+        let number_state = state.state.downcast_mut::<NumberInputState>();
         let child = &mut state.children[0];
+        let is_focused = child.state.downcast_ref::<text_input::State>().is_focused();
 
         if self.bounds.0 == self.bounds.1 {
             return event::Status::Ignored;
         }
 
-        if layout
-            .bounds()
-            .contains(cursor.position().unwrap_or_default())
-        {
-            if mouse_over_inc || mouse_over_dec {
-                let mut event_status = event::Status::Captured;
-                match event {
-                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                        if mouse_over_dec {
-                            modifiers.decrease_pressed = true;
-                            self.decrease_val(shell);
-                        } else if mouse_over_inc {
-                            modifiers.increase_pressed = true;
-                            self.increase_val(shell);
-                        } else {
-                            event_status = event::Status::Ignored;
-                        }
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                number_state.keyboard_modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                number_state.modifiers.decrease_pressed = false;
+                number_state.modifiers.increase_pressed = false;
+                number_state.next_repeat = None;
+                number_state.scrub_origin = None;
+
+                if number_state.is_scrubbing {
+                    number_state.is_scrubbing = false;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(origin) = number_state.scrub_origin {
+                    if !number_state.is_scrubbing && (position.x - origin).abs() > SCRUB_THRESHOLD {
+                        number_state.is_scrubbing = true;
+                        number_state.scrub_accumulator = 0.0;
+                        number_state.text = None;
+                        child.state.downcast_mut::<text_input::State>().unfocus();
                     }
-                    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                        if mouse_over_dec {
-                            modifiers.decrease_pressed = false;
-                        } else if mouse_over_inc {
-                            modifiers.increase_pressed = false;
-                        } else {
-                            event_status = event::Status::Ignored;
-                        }
+
+                    if number_state.is_scrubbing {
+                        let step = self.scaled_step(number_state.keyboard_modifiers);
+                        let amount = (position.x - origin) / SCRUB_PIXELS_PER_STEP;
+                        number_state.scrub_origin = Some(position.x);
+                        self.accumulate(&mut number_state.scrub_accumulator, amount, &step, shell);
+                        return event::Status::Captured;
                     }
-                    _ => event_status = event::Status::Ignored,
                 }
-                event_status
-            } else {
-                match event {
-                    Event::Keyboard(keyboard::Event::CharacterReceived(c))
-                        if child.state.downcast_mut::<text_input::State>().is_focused()
-                            && c.is_numeric() =>
-                    {
-                        let mut new_val = self.value.to_string();
-                        match child
-                            .state
-                            .downcast_mut::<text_input::State>()
-                            .cursor()
-                            .state(&Value::new(&new_val))
-                        {
-                            cursor::State::Index(idx) => {
-                                if T::zero().eq(&self.value) {
-                                    new_val = c.to_string();
-                                } else {
-                                    new_val.insert(idx, c);
-                                }
-                            }
-                            cursor::State::Selection { start, end } => {
-                                if (0..new_val.len()).contains(&start)
-                                    && (0..new_val.len()).contains(&end)
-                                {
-                                    new_val.replace_range(
-                                        if start > end { end..start } else { start..end },
-                                        &c.to_string(),
-                                    );
-                                }
-                            }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(next_repeat) = number_state.next_repeat {
+                    if now >= next_repeat {
+                        let step = self.scaled_step(number_state.keyboard_modifiers);
+                        if number_state.modifiers.decrease_pressed && mouse_over_dec {
+                            self.decrease_val(&step, shell);
+                        } else if number_state.modifiers.increase_pressed && mouse_over_inc {
+                            self.increase_val(&step, shell);
                         }
 
-                        match T::from_str(&new_val) {
-                            Ok(val) => {
-                                if (self.bounds.0..=self.bounds.1).contains(&val) {
-                                    self.value = val;
-                                    shell.publish((self.on_change)(self.value));
-                                    self.content.on_event(
-                                        child,
-                                        event.clone(),
-                                        content,
-                                        cursor,
-                                        renderer,
-                                        clipboard,
-                                        shell,
-                                        viewport,
-                                    )
-                                } else {
-                                    event::Status::Ignored
-                                }
-                            }
-                            Err(_) => event::Status::Ignored,
-                        }
-                    }
-                    Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
-                        if child.state.downcast_mut::<text_input::State>().is_focused() =>
-                    {
-                        match key_code {
-                            keyboard::KeyCode::Up => {
-                                self.increase_val(shell);
-                                event::Status::Captured
-                            }
-                            keyboard::KeyCode::Down => {
-                                self.decrease_val(shell);
-                                event::Status::Captured
-                            }
-                            keyboard::KeyCode::Backspace => {
-                                if T::zero().eq(&self.value) {
-                                    event::Status::Ignored
-                                } else {
-                                    let mut new_val = self.value.to_string();
-                                    match child
-                                        .state
-                                        .downcast_mut::<text_input::State>()
-                                        .cursor()
-                                        .state(&Value::new(&new_val))
-                                    {
-                                        cursor::State::Index(idx) => {
-                                            if idx >= 1 && idx <= new_val.len() {
-                                                if new_val.len() == 1 {
-                                                    new_val = if self.bounds.0 > T::zero() {
-                                                        self.bounds.0
-                                                    } else {
-                                                        T::zero()
-                                                    }
-                                                    .to_string();
-                                                } else {
-                                                    let _ = new_val.remove(idx - 1);
-                                                }
-                                            }
-                                        }
-                                        cursor::State::Selection { start, end } => {
-                                            if (0..new_val.len()).contains(&start)
-                                                && (0..new_val.len()).contains(&end)
-                                            {
-                                                new_val.replace_range(
-                                                    if start > end {
-                                                        end..start
-                                                    } else {
-                                                        start..end
-                                                    },
-                                                    "",
-                                                );
-                                            }
-                                        }
-                                    }
-
-                                    match T::from_str(&new_val) {
-                                        Ok(val) => {
-                                            if (self.bounds.0..=self.bounds.1).contains(&val) {
-                                                self.value = val;
-                                                shell.publish((self.on_change)(self.value));
-                                                self.content.on_event(
-                                                    child,
-                                                    event.clone(),
-                                                    content,
-                                                    cursor,
-                                                    renderer,
-                                                    clipboard,
-                                                    shell,
-                                                    viewport,
-                                                )
-                                            } else {
-                                                event::Status::Ignored
-                                            }
-                                        }
-                                        Err(_) => event::Status::Ignored,
-                                    }
-                                }
-                            }
-                            _ => self.content.on_event(
-                                child,
-                                event.clone(),
-                                content,
-                                cursor,
-                                renderer,
-                                clipboard,
-                                shell,
-                                viewport,
-                            ),
-                        }
+                        number_state.repeats = number_state.repeats.saturating_add(1);
+                        number_state.next_repeat =
+                            Some(now + repeat_interval(number_state.repeats));
                     }
 
-                    Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                        let positive = match delta {
-                            mouse::ScrollDelta::Lines { y, .. }
-                            | mouse::ScrollDelta::Pixels { y, .. } => y.is_sign_positive(),
-                        };
-                        if positive {
-                            self.increase_val(shell);
-                        } else {
-                            self.decrease_val(shell);
-                        }
-                        event::Status::Captured
+                    if let Some(next_repeat) = number_state.next_repeat {
+                        shell.request_redraw(window::RedrawRequest::At(next_repeat));
                     }
-                    _ => self.content.on_event(
-                        child, event, content, cursor, renderer, clipboard, shell, viewport,
-                    ),
                 }
             }
-        } else {
+            _ => {}
+        }
+
+        if cursor.is_over(layout.bounds()) && (mouse_over_inc || mouse_over_dec) {
+            let mut event_status = event::Status::Captured;
             match event {
-                Event::Keyboard(_) => event::Status::Ignored,
-                _ => self.content.on_event(
-                    child, event, content, cursor, renderer, clipboard, shell, viewport,
-                ),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    let step = self.scaled_step(number_state.keyboard_modifiers);
+                    if mouse_over_dec {
+                        number_state.modifiers.decrease_pressed = true;
+                        self.decrease_val(&step, shell);
+                    } else if mouse_over_inc {
+                        number_state.modifiers.increase_pressed = true;
+                        self.increase_val(&step, shell);
+                    } else {
+                        event_status = event::Status::Ignored;
+                    }
+
+                    if event_status == event::Status::Captured {
+                        let next_repeat = Instant::now() + REPEAT_DELAY;
+                        number_state.repeats = 0;
+                        number_state.next_repeat = Some(next_repeat);
+                        shell.request_redraw(window::RedrawRequest::At(next_repeat));
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. }) => {}
+                _ => event_status = event::Status::Ignored,
+            }
+            return event_status;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Up,
+                modifiers,
+            }) if is_focused => {
+                self.increase_val(&self.scaled_step(modifiers), shell);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                modifiers,
+            }) if is_focused => {
+                self.decrease_val(&self.scaled_step(modifiers), shell);
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let amount = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / SCROLL_PIXELS_PER_STEP,
+                };
+                let step = self.scaled_step(number_state.keyboard_modifiers);
+                self.accumulate(&mut number_state.scroll_accumulator, amount, &step, shell);
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if !is_focused && cursor.is_over(content.bounds()) =>
+            {
+                number_state.scrub_origin = cursor.position().map(|position| position.x);
+            }
+            _ => {}
+        }
+
+        let mut value = Value::new(&self.text(number_state, is_focused));
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let status = text_input::update(
+            event,
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &mut value,
+            self.size,
+            LineHeight::default(),
+            Some(self.font),
+            false,
+            Some(&InternalMessage::Input),
+            None,
+            &Some(InternalMessage::Submit),
+            || child.state.downcast_mut::<text_input::State>(),
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        for message in messages {
+            match message {
+                InternalMessage::Input(text) => self.input(number_state, text, shell),
+                InternalMessage::Submit => match self.commit(number_state, shell) {
+                    Ok(()) => {
+                        if let Some(on_submit) = self.on_submit.clone() {
+                            shell.publish(on_submit);
+                        }
+                    }
+                    Err(error) => number_state.error = Some(error),
+                },
             }
         }
+
+        match (
+            is_focused,
+            child.state.downcast_ref::<text_input::State>().is_focused(),
+        ) {
+            (false, true) => self.focus(number_state),
+            (true, false) => self.blur(number_state, shell),
+            _ => {}
+        }
+
+        status
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         _viewport: &Rectangle,
@@ -631,7 +1025,9 @@ where
         let mouse_over_decrease = dec_bounds.contains(cursor.position().unwrap_or_default());
         let mouse_over_increase = inc_bounds.contains(cursor.position().unwrap_or_default());
 
-        if (mouse_over_decrease && !is_decrease_disabled)
+        if state.state.downcast_ref::<NumberInputState>().is_scrubbing {
+            mouse::Interaction::ResizingHorizontally
+        } else if (mouse_over_decrease && !is_decrease_disabled)
             || (mouse_over_increase && !is_increase_disabled)
         {
             mouse::Interaction::Pointer
@@ -666,20 +1062,66 @@ where
             .next()
             .expect("fail to get decreate mod layout")
             .bounds();
+        let number_state = state.state.downcast_ref::<NumberInputState>();
+        let is_focused = state.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
         self.content.draw(
             &state.children[0],
             renderer,
             theme,
             content_layout,
             cursor,
-            None,
+            Some(&Value::new(&self.text(number_state, is_focused))),
         );
+
+        let error = self.error(number_state, is_focused);
+        let field_style = if error.is_some() {
+            theme.invalid(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        if let Some(border_color) = field_style.border_color {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: content_layout.bounds(),
+                    border_radius: (2.0).into(),
+                    border_width: 1.0,
+                    border_color,
+                },
+                Background::Color(Color::TRANSPARENT),
+            );
+        }
+
+        let txt_size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        if let (Some(message_layout), Some(error)) = (children.next(), error) {
+            let message_bounds = message_layout.bounds();
+
+            renderer.fill_text(core::text::Text {
+                content: &error,
+                bounds: Rectangle {
+                    y: message_bounds.center_y(),
+                    ..message_bounds
+                },
+                size: txt_size * MESSAGE_SIZE_FACTOR,
+                color: field_style.message_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                line_height: LineHeight::default(),
+                shaping: iced_widget::text::Shaping::Advanced,
+            });
+        }
+
         let is_decrease_disabled = self.value <= self.bounds.0 || self.bounds.0 == self.bounds.1;
         let is_increase_disabled = self.value >= self.bounds.1 || self.bounds.0 == self.bounds.1;
 
         let decrease_btn_style = if is_decrease_disabled {
             theme.disabled(&self.style)
-        } else if state.state.downcast_ref::<ModifierState>().decrease_pressed {
+        } else if number_state.modifiers.decrease_pressed {
             theme.pressed(&self.style)
         } else {
             theme.active(&self.style)
@@ -687,14 +1129,12 @@ where
 
         let increase_btn_style = if is_increase_disabled {
             theme.disabled(&self.style)
-        } else if state.state.downcast_ref::<ModifierState>().increase_pressed {
+        } else if number_state.modifiers.increase_pressed {
             theme.pressed(&self.style)
         } else {
             theme.active(&self.style)
         };
 
-        let txt_size = self.size.unwrap_or_else(|| renderer.default_size());
-
         let icon_size = txt_size * 2.5 / 4.0;
 
         // decrease button section
@@ -768,10 +1208,47 @@ pub struct ModifierState {
     pub increase_pressed: bool,
 }
 
+/// The local state of a [`NumberInput`].
+#[derive(Default, Clone, Debug)]
+struct NumberInputState {
+    /// The state of the modifier buttons.
+    modifiers: ModifierState,
+    /// The point in time the pressed modifier button repeats its step.
+    next_repeat: Option<Instant>,
+    /// The number of repeated steps of the pressed modifier button.
+    repeats: u32,
+    /// The pressed keyboard modifiers.
+    keyboard_modifiers: keyboard::Modifiers,
+    /// The accumulated scroll amount not yet turned into a step.
+    scroll_accumulator: f32,
+    /// The horizontal position of the cursor while it may scrub the value.
+    scrub_origin: Option<f32>,
+    /// The accumulated scrub amount not yet turned into a step.
+    scrub_accumulator: f32,
+    /// Whether the value is currently scrubbed by dragging the cursor.
+    is_scrubbing: bool,
+    /// The text typed into the [`NumberInput`] while it is focused.
+    text: Option<String>,
+    /// The formatted value the typed text belongs to. If the value changes
+    /// from the outside, the typed text is replaced.
+    synced: String,
+    /// The error of the typed or submitted text, e.g. an invalid expression.
+    error: Option<String>,
+}
+
+/// The messages of the underlying text input of a [`NumberInput`].
+#[derive(Clone, Debug)]
+enum InternalMessage {
+    /// The text of the text input changed.
+    Input(String),
+    /// The enter key was pressed.
+    Submit,
+}
+
 impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    T: 'a + Num + NumAssignOps + PartialOrd + Display + FromStr + Copy,
+    T: 'a + Number,
     Message: 'a + Clone,
     Renderer: 'a + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: number_input::StyleSheet
@@ -783,3 +1260,40 @@ where
         Element::new(num_input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::Shell;
+
+    use super::{NumberInput, NumberInputState};
+
+    #[test]
+    fn focus_blur_keeps_value_test() {
+        for value in [99_999.99_f32, 1_234_567.0, 16_777_215.0, 0.1 + 0.2] {
+            let mut number_input: NumberInput<'_, f32, f32> =
+                NumberInput::new(value, f32::MAX, |value| value).precision(1);
+            let mut state = NumberInputState::default();
+            let mut messages = Vec::new();
+
+            number_input.focus(&mut state);
+            number_input.blur(&mut state, &mut Shell::new(&mut messages));
+
+            assert!(messages.is_empty(), "{value} changed to {messages:?}");
+            assert_eq!(number_input.value.to_bits(), value.to_bits());
+        }
+    }
+
+    #[test]
+    fn blur_commits_edited_text_test() {
+        let mut number_input: NumberInput<'_, f32, f32> =
+            NumberInput::new(1.5, 100.0, |value| value);
+        let mut state = NumberInputState::default();
+        let mut messages = Vec::new();
+
+        number_input.focus(&mut state);
+        state.text = Some(String::from("2*3"));
+        number_input.blur(&mut state, &mut Shell::new(&mut messages));
+
+        assert_eq!(messages, [6.0]);
+    }
+}
@@ -0,0 +1,261 @@
+//! The dropdown list of a combo box.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+use crate::native::{
+    combo_box::{InternalMessage, State},
+    selection_list::list::ListState,
+};
+use crate::style::selection_list::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        event::Status,
+        keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer, touch,
+        widget::tree::Tree,
+        Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    },
+    scrollable::{self, AbsoluteOffset},
+};
+
+/// The width of the border around the dropdown list.
+const BORDER_WIDTH: f32 = 1.0;
+
+/// The overlay of the [`ComboBox`](crate::native::ComboBox).
+#[allow(missing_debug_implementations)]
+pub struct ComboBoxOverlay<'a, T, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state of the dropdown list.
+    tree: &'a mut Tree,
+    /// The scrollable list of options.
+    content: Element<'a, InternalMessage, Renderer>,
+    /// The state shared between [`ComboBox`](crate::native::ComboBox) and [`ComboBoxOverlay`].
+    state: &'a mut State,
+    /// The options of the list.
+    options: &'a [T],
+    /// The message to produce when an option is selected.
+    on_selected: &'a dyn Fn(usize, T) -> Message,
+    /// The bounds of the field the list is shown below or above.
+    target: Rectangle,
+    /// The maximum height of the list.
+    max_height: f32,
+    /// The style of the list.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBoxOverlay<'a, T, Message, Renderer>
+where
+    T: 'a + Clone,
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ComboBoxOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        tree: &'a mut Tree,
+        content: Element<'a, InternalMessage, Renderer>,
+        state: &'a mut State,
+        options: &'a [T],
+        on_selected: &'a dyn Fn(usize, T) -> Message,
+        target: Rectangle,
+        max_height: f32,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        ComboBoxOverlay {
+            tree,
+            content,
+            state,
+            options,
+            on_selected,
+            target,
+            max_height,
+            style,
+        }
+    }
+
+    /// Turn this [`ComboBoxOverlay`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(self.target.position(), Box::new(self))
+    }
+}
+
+impl<T, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for ComboBoxOverlay<'_, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let width = self.target.width;
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                width - BORDER_WIDTH * 2.0,
+                self.max_height - BORDER_WIDTH * 2.0,
+            ),
+        )
+        .width(Length::Fill);
+
+        let mut content = self.content.as_widget().layout(renderer, &limits);
+        content.move_to(Point::new(BORDER_WIDTH, BORDER_WIDTH));
+
+        let size = Size::new(width, content.size().height + BORDER_WIDTH * 2.0);
+
+        // Open upwards if the list does not fit below the field.
+        let below = position.y + self.target.height;
+        let y = if below + size.height > bounds.height && position.y >= size.height {
+            position.y - size.height
+        } else {
+            below
+        };
+
+        let mut node = Node::with_children(size, vec![content]);
+        node.move_to(Point::new(position.x, y));
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: BORDER_WIDTH,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> Status {
+        let bounds = layout.bounds();
+        let is_over = cursor.is_over(bounds);
+
+        // Typing goes to the field, only the keys moving through the list and
+        // selecting an option are handled here.
+        let is_handled = match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => matches!(
+                key_code,
+                keyboard::KeyCode::Up
+                    | keyboard::KeyCode::Down
+                    | keyboard::KeyCode::PageUp
+                    | keyboard::KeyCode::PageDown
+                    | keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+            ),
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => is_over,
+            Event::Mouse(_) | Event::Touch(_) => true,
+            Event::Keyboard(_) | Event::Window(_) => false,
+        };
+        if !is_handled {
+            return Status::Ignored;
+        }
+
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let status = self.content.as_widget_mut().on_event(
+            self.tree,
+            event.clone(),
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &bounds,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        // Scroll the option hovered with the keyboard into view.
+        let list_state = self.tree.children[0].state.downcast_mut::<ListState>();
+        if let Some(offset) = list_state.scroll_to.take() {
+            self.tree
+                .state
+                .downcast_mut::<scrollable::State>()
+                .scroll_to(AbsoluteOffset { x: 0.0, y: offset });
+        }
+
+        for message in messages {
+            if let InternalMessage::Selected(index) = message {
+                if let Some(option) = self.options.get(index) {
+                    shell.publish((self.on_selected)(index, option.clone()));
+                }
+                self.state.close();
+            }
+        }
+
+        // Presses on the list must not unfocus the field.
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => Status::Captured,
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+}
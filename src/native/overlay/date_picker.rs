@@ -2,7 +2,6 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
-use chrono::{Datelike, Local, NaiveDate};
 use iced_widget::{
     button, container,
     core::{
@@ -248,24 +247,21 @@ where
                             let (day, is_in_month) = crate::core::date::position_to_day(
                                 x,
                                 y,
-                                self.state.date.year(),
-                                self.state.date.month(),
+                                self.state.date.year,
+                                self.state.date.month,
                             );
 
-                            self.state.date = match is_in_month {
+                            let date = match is_in_month {
                                 IsInMonth::Previous => {
                                     crate::core::date::pred_month(self.state.date)
-                                        .with_day(day as u32)
-                                        .expect("Previous month with day should be valid")
                                 }
-                                IsInMonth::Same => self
-                                    .state
-                                    .date
-                                    .with_day(day as u32)
-                                    .expect("Same month with day should be valid"),
-                                IsInMonth::Next => crate::core::date::succ_month(self.state.date)
-                                    .with_day(day as u32)
-                                    .expect("Succeeding month with day should be valid"),
+                                IsInMonth::Same => self.state.date,
+                                IsInMonth::Next => crate::core::date::succ_month(self.state.date),
+                            };
+
+                            self.state.date = Date {
+                                day: day as u32,
+                                ..date
                             };
 
                             status = event::Status::Captured;
@@ -611,7 +607,7 @@ where
         );
 
         if !fake_messages.is_empty() {
-            shell.publish((self.on_submit)(self.state.date.into()));
+            shell.publish((self.on_submit)(self.state.date));
         }
 
         month_year_status
@@ -867,7 +863,7 @@ where
 #[derive(Debug)]
 pub struct State {
     /// The selected date of the [`DatePickerOverlay`].
-    pub(crate) date: NaiveDate,
+    pub(crate) date: Date,
     /// The focus of the [`DatePickerOverlay`].
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
@@ -877,7 +873,7 @@ pub struct State {
 impl State {
     /// Creates a new State with the given date.
     #[must_use]
-    pub fn new(date: NaiveDate) -> Self {
+    pub fn new(date: Date) -> Self {
         Self {
             date,
             ..Self::default()
@@ -888,7 +884,7 @@ impl State {
 impl Default for State {
     fn default() -> Self {
         Self {
-            date: Local::now().naive_local().date(),
+            date: Date::today(),
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
         }
@@ -1194,7 +1190,7 @@ fn month_year<Theme>(
 fn days<Theme>(
     renderer: &mut Renderer<Theme>,
     layout: Layout<'_>,
-    date: chrono::NaiveDate,
+    date: Date,
     cursor: Point,
     //style: &Style,
     style: &HashMap<StyleState, Appearance>,
@@ -1249,7 +1245,7 @@ fn day_labels<Theme>(
 fn day_table<Theme>(
     renderer: &mut Renderer<Theme>,
     children: &mut dyn Iterator<Item = Layout<'_>>,
-    date: chrono::NaiveDate,
+    date: Date,
     cursor: Point,
     style: &HashMap<StyleState, Appearance>,
     focus: Focus,
@@ -1260,11 +1256,11 @@ fn day_table<Theme>(
         for (x, label) in row.children().enumerate() {
             let bounds = label.bounds();
             let (number, is_in_month) =
-                crate::core::date::position_to_day(x, y, date.year(), date.month());
+                crate::core::date::position_to_day(x, y, date.year, date.month);
 
             let mouse_over = bounds.contains(cursor);
 
-            let selected = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let selected = date.day == number as u32 && is_in_month == IsInMonth::Same;
 
             let mut style_state = StyleState::Active;
             if selected {
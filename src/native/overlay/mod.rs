@@ -29,3 +29,13 @@ pub use time_picker::{State, TimePickerOverlay};
 pub mod context_menu;
 #[cfg(feature = "context_menu")]
 pub use context_menu::ContextMenuOverlay;
+
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+pub use combo_box::ComboBoxOverlay;
+
+#[cfg(feature = "tab_bar")]
+pub mod tab_bar;
+#[cfg(feature = "tab_bar")]
+pub use tab_bar::TabTooltipOverlay;
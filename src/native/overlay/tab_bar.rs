@@ -0,0 +1,142 @@
+//! The tooltip of a tab on a tab bar.
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+use crate::style::tab_bar::StyleSheet;
+
+use iced_widget::core::{
+    self,
+    alignment::{Horizontal, Vertical},
+    layout::Node,
+    mouse::Cursor,
+    overlay, renderer,
+    text::{LineHeight, Shaping},
+    Font, Layout, Point, Rectangle, Size,
+};
+
+/// The padding around the text of the tooltip.
+const PADDING: f32 = 5.0;
+/// The distance between the cursor and the tooltip below it.
+const CURSOR_GAP: f32 = 16.0;
+
+/// The tooltip of a tab of a [`TabBar`](crate::native::TabBar), following
+/// the cursor.
+#[allow(missing_debug_implementations)]
+pub struct TabTooltipOverlay<'a, Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The text of the tooltip.
+    tooltip: &'a str,
+    /// The position of the cursor over the tab.
+    cursor_position: Point,
+    /// The font of the text.
+    font: Font,
+    /// The size of the text.
+    text_size: f32,
+    /// The style of the [`TabBar`](crate::native::TabBar).
+    style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Renderer> TabTooltipOverlay<'a, Renderer>
+where
+    Renderer: 'a + core::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TabTooltipOverlay`].
+    pub(crate) fn new(
+        tooltip: &'a str,
+        cursor_position: Point,
+        font: Font,
+        text_size: f32,
+        style: &'a <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        TabTooltipOverlay {
+            tooltip,
+            cursor_position,
+            font,
+            text_size,
+            style,
+        }
+    }
+
+    /// Turn this [`TabTooltipOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay<Message>(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(self.cursor_position, Box::new(self))
+    }
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Renderer> for TabTooltipOverlay<'_, Renderer>
+where
+    Renderer: core::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let text_size = renderer.measure(
+            self.tooltip,
+            self.text_size,
+            LineHeight::default(),
+            self.font,
+            bounds,
+            Shaping::Advanced,
+        );
+        let size = Size::new(
+            text_size.width + PADDING * 2.0,
+            text_size.height + PADDING * 2.0,
+        );
+
+        // The tooltip is kept inside the window, above the cursor if it does
+        // not fit below.
+        let x = position.x.min(bounds.width - size.width).max(0.0);
+        let below = position.y + CURSOR_GAP;
+        let y = if below + size.height > bounds.height {
+            (position.y - size.height).max(0.0)
+        } else {
+            below
+        };
+
+        let mut node = Node::new(size);
+        node.move_to(Point::new(x, y));
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style, true);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: appearance.tab_label_border_width,
+                border_color: appearance.tab_label_border_color,
+            },
+            appearance.tab_label_background,
+        );
+
+        renderer.fill_text(core::text::Text {
+            content: self.tooltip,
+            bounds: Rectangle {
+                x: bounds.x + PADDING,
+                y: bounds.center_y(),
+                width: bounds.width - PADDING * 2.0,
+                ..bounds
+            },
+            size: self.text_size,
+            color: appearance.text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            line_height: LineHeight::default(),
+            shaping: Shaping::Advanced,
+        });
+    }
+}
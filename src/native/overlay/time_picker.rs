@@ -1,7 +1,7 @@
 //! Use a time picker as an input element for picking times.
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use crate::graphics::icons::{icon_to_char, ICON_FONT};
 use crate::time_picker::{self, Time};
@@ -15,7 +15,6 @@ use crate::{
     style::style_state::StyleState,
     Icon,
 };
-use chrono::{Duration, Local, NaiveTime, Timelike};
 
 use iced_widget::{
     button,
@@ -41,6 +40,10 @@ use iced_widget::{
 
 pub use crate::style::time_picker::{Appearance, StyleSheet};
 
+/// The number of seconds of an hour.
+const SECONDS_PER_HOUR: u64 = 60 * 60;
+/// The number of seconds of a minute.
+const SECONDS_PER_MINUTE: u64 = 60;
 /// The padding around the elements.
 const PADDING: f32 = 10.0;
 /// The spacing between the elements.
@@ -185,22 +188,10 @@ where
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
                     NearestRadius::Period => {
-                        let (pm, hour) = self.state.time.hour12();
-                        let hour = if hour == 12 {
-                            if pm {
-                                12
-                            } else {
-                                0
-                            }
-                        } else {
-                            hour
-                        };
+                        let hour = self.state.time.hour24();
 
-                        self.state.time = self
-                            .state
-                            .time
-                            .with_hour(if pm && hour != 12 { hour } else { hour + 12 } % 24)
-                            .expect("New time with hour should be valid");
+                        self.state.time =
+                            self.state.time.with_hour24((hour + 12) % 24, Period::H24);
                         event::Status::Captured
                     }
                     NearestRadius::Hour => {
@@ -240,11 +231,10 @@ where
 
                     let (pm, _) = self.state.time.hour12();
 
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
-                        .expect("New time with hour should be valid");
+                    self.state.time = self.state.time.with_hour24(
+                        (nearest_point as u32 + if pm { 12 } else { 0 }) % 24,
+                        Period::H24,
+                    );
                     event::Status::Captured
                 }
                 ClockDragged::Minute => {
@@ -255,11 +245,7 @@ where
                         cursor.position().unwrap_or_default(),
                     );
 
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_minute(nearest_point as u32)
-                        .expect("New time with minute should be valid");
+                    self.state.time = self.state.time.with_minute(nearest_point as u32);
                     event::Status::Captured
                 }
                 ClockDragged::Second => {
@@ -270,11 +256,7 @@ where
                         cursor.position().unwrap_or_default(),
                     );
 
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_second(nearest_point as u32)
-                        .expect("New time with second should be valid");
+                    self.state.time = self.state.time.with_second(nearest_point as u32);
                     event::Status::Captured
                 }
                 ClockDragged::None => event::Status::Ignored,
@@ -341,20 +323,18 @@ where
             .next()
             .expect("Native: Layout should have a down arrow for minutes");
 
-        let calculate_time = |time: &mut NaiveTime,
-                              up_arrow: Layout<'_>,
-                              down_arrow: Layout<'_>,
-                              duration: Duration| {
-            if cursor.is_over(up_arrow.bounds()) {
-                *time += duration;
-                event::Status::Captured
-            } else if cursor.is_over(down_arrow.bounds()) {
-                *time -= duration;
-                event::Status::Captured
-            } else {
-                event::Status::Ignored
-            }
-        };
+        let calculate_time =
+            |time: &mut Time, up_arrow: Layout<'_>, down_arrow: Layout<'_>, seconds: u64| {
+                if cursor.is_over(up_arrow.bounds()) {
+                    *time = *time + Duration::from_secs(seconds);
+                    event::Status::Captured
+                } else if cursor.is_over(down_arrow.bounds()) {
+                    *time = *time - Duration::from_secs(seconds);
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            };
 
         let digital_clock_status = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
@@ -366,7 +346,7 @@ where
                         &mut self.state.time,
                         hour_up_arrow,
                         hour_down_arrow,
-                        Duration::hours(1),
+                        SECONDS_PER_HOUR,
                     )
                 } else if cursor.is_over(minute_layout.bounds()) {
                     self.state.focus = Focus::DigitalMinute;
@@ -375,7 +355,7 @@ where
                         &mut self.state.time,
                         minute_up_arrow,
                         minute_down_arrow,
-                        Duration::minutes(1),
+                        SECONDS_PER_MINUTE,
                     )
                 } else {
                     event::Status::Ignored
@@ -406,12 +386,7 @@ where
                     if cursor.is_over(second_layout.bounds()) {
                         self.state.focus = Focus::DigitalSecond;
 
-                        calculate_time(
-                            &mut self.state.time,
-                            second_up_arrow,
-                            second_down_arrow,
-                            Duration::seconds(1),
-                        )
+                        calculate_time(&mut self.state.time, second_up_arrow, second_down_arrow, 1)
                     } else {
                         event::Status::Ignored
                     }
@@ -456,29 +431,27 @@ where
                 }
             } else {
                 let mut keyboard_handle =
-                    |key_code: &keyboard::KeyCode, time: &mut NaiveTime, duration: Duration| {
-                        match key_code {
-                            keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
-                                *time -= duration;
-                                status = event::Status::Captured;
-                            }
-                            keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
-                                *time += duration;
-                                status = event::Status::Captured;
-                            }
-                            _ => {}
+                    |key_code: &keyboard::KeyCode, time: &mut Time, seconds: u64| match key_code {
+                        keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                            *time = *time - Duration::from_secs(seconds);
+                            status = event::Status::Captured;
+                        }
+                        keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                            *time = *time + Duration::from_secs(seconds);
+                            status = event::Status::Captured;
                         }
+                        _ => {}
                     };
 
                 match self.state.focus {
                     Focus::DigitalHour => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::hours(1));
+                        keyboard_handle(key_code, &mut self.state.time, SECONDS_PER_HOUR);
                     }
                     Focus::DigitalMinute => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::minutes(1));
+                        keyboard_handle(key_code, &mut self.state.time, SECONDS_PER_MINUTE);
                     }
                     Focus::DigitalSecond => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::seconds(1));
+                        keyboard_handle(key_code, &mut self.state.time, 1);
                     }
                     _ => {}
                 }
@@ -662,7 +635,7 @@ where
 
         if !fake_messages.is_empty() {
             let (hour, period) = if self.state.use_24h {
-                (self.state.time.hour(), Period::H24)
+                (self.state.time.hour24(), Period::H24)
             } else {
                 let (period, hour) = self.state.time.hour12();
                 (hour, if period { Period::Pm } else { Period::Am })
@@ -957,7 +930,7 @@ where
                         .height(Length::Fixed(arrow_size)),
                 )
                 .push(
-                    text::Text::new(format!("{:02}", time_picker.state.time.hour()))
+                    text::Text::new(format!("{:02}", time_picker.state.time.hour24()))
                         .size(font_size),
                 )
                 .push(
@@ -983,7 +956,7 @@ where
                         .height(Length::Fixed(arrow_size)),
                 )
                 .push(
-                    text::Text::new(format!("{:02}", time_picker.state.time.hour()))
+                    text::Text::new(format!("{:02}", time_picker.state.time.hour24()))
                         .size(font_size),
                 )
                 .push(
@@ -1012,7 +985,7 @@ where
                             .height(Length::Fixed(arrow_size)),
                     )
                     .push(
-                        text::Text::new(format!("{:02}", time_picker.state.time.hour()))
+                        text::Text::new(format!("{:02}", time_picker.state.time.hour24()))
                             .size(font_size),
                     )
                     .push(
@@ -1201,7 +1174,7 @@ fn draw_clock<Message, Theme>(
             hour_points.iter().enumerate().for_each(|(i, p)| {
                 let (pm, selected) = {
                     let (pm, _) = time_picker.state.time.hour12();
-                    let hour = time_picker.state.time.hour();
+                    let hour = time_picker.state.time.hour24();
                     (pm, hour % 12 == i as u32)
                 };
 
@@ -1492,7 +1465,7 @@ fn draw_digital_clock<Message, Theme>(
         format!(
             "{:02}",
             if time_picker.state.use_24h {
-                time_picker.state.time.hour()
+                time_picker.state.time.hour24()
             } else {
                 time_picker.state.time.hour12().1
             }
@@ -1601,7 +1574,7 @@ fn draw_digital_clock<Message, Theme>(
 #[derive(Debug)]
 pub struct State {
     /// The selected time of the [`TimePickerOverlay`].
-    pub(crate) time: NaiveTime,
+    pub(crate) time: Time,
     /// Toggle if the cache needs to be cleared.
     pub(crate) clock_cache_needs_clearance: bool,
     /// The cache of the clock of the [`TimePickerOverlay`].
@@ -1623,7 +1596,12 @@ impl State {
     #[must_use]
     pub fn new(time: Time) -> Self {
         Self {
-            time: time.into(),
+            time: Time::Hms {
+                hour: time.hour24(),
+                minute: time.minute(),
+                second: time.second(),
+                period: Period::H24,
+            },
             ..Self::default()
         }
     }
@@ -1632,7 +1610,7 @@ impl State {
 impl Default for State {
     fn default() -> Self {
         Self {
-            time: Local::now().naive_local().time(),
+            time: Time::now_hms(true),
             clock_cache_needs_clearance: false,
             clock_cache: canvas::Cache::new(),
             use_24h: false,
@@ -9,20 +9,48 @@ use iced_widget::{
         layout::{Limits, Node},
         mouse::{self, Cursor},
         renderer,
-        widget::Tree,
-        Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+        widget::{Operation, Tree},
+        Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
     },
     runtime::Font,
-    scrollable, text,
+    scrollable::{self, AbsoluteOffset},
+    text,
     text::LineHeight,
-    Container, Scrollable,
+    text_input, Container, Scrollable, TextInput,
 };
 
-pub use list::List;
-use std::hash::Hash;
-use std::{fmt::Display, marker::PhantomData};
+pub use crate::core::search::SearchMode;
+use list::{Entry, Key, ListState, OnReorder, OnSelected, OnSelectionChange, RowBuilder};
+pub use list::{List, RowState};
+use std::hash::{Hash, Hasher};
+use std::{
+    borrow::Cow,
+    cell::{OnceCell, RefCell},
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    marker::PhantomData,
+    rc::Rc,
+};
 
 /// A widget for selecting a single value from a dynamic scrollable list of options.
+///
+/// Once clicked, the list can be navigated with the arrow, page and home/end
+/// keys and an option is selected with enter. Typing jumps to the first option
+/// starting with the typed text.
+///
+/// With [`multi_select`](Self::multi_select) multiple values can be selected:
+/// ctrl-click (cmd-click on macOS) toggles an option and shift-click selects
+/// the range from the last clicked option.
+///
+/// With [`search`](Self::search) a search field is shown above the list,
+/// which filters the options by the query and highlights the matched
+/// characters. With [`group_by`](Self::group_by) the options are grouped into
+/// sections with sticky headers.
+///
+/// The options can be borrowed or owned. The selected and hovered options are
+/// identified by their hash, or by the key set with [`key`](Self::key), so
+/// they stay selected and hovered when options are inserted, removed or
+/// sorted.
 #[allow(missing_debug_implementations)]
 #[allow(clippy::type_repetition_in_bounds)]
 pub struct SelectionList<'a, T, Message, Renderer = crate::Renderer>
@@ -30,12 +58,12 @@ where
     T: Clone + ToString + Eq + Hash,
     [T]: ToOwned<Owned = Vec<T>>,
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
-    Renderer::Theme: StyleSheet + container::StyleSheet,
+    Renderer::Theme: StyleSheet + container::StyleSheet + text_input::StyleSheet,
 {
-    /// Container for Rendering List.
-    container: Container<'a, Message, Renderer>,
+    /// Container for Rendering List, built from the settings on first use.
+    container: OnceCell<Container<'a, Message, Renderer>>,
     /// List of Elements to Render.
-    options: &'a [T],
+    options: Rc<Cow<'a, [T]>>,
     /// Label Font
     font: Renderer::Font,
     /// The Containers Width
@@ -48,14 +76,40 @@ where
     text_size: f32,
     /// Style for Looks
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The message to produce when a single option is selected.
+    on_selected: OnSelected<T, Message>,
+    /// The message to produce when the selection of a multi-select list changes.
+    on_selection_change: Option<OnSelectionChange<T, Message>>,
+    /// Set the Selected ID manually.
+    selected: Option<usize>,
+    /// Set the selected IDs of a multi-select list manually.
+    selection: Option<Vec<usize>>,
+    /// The search field shown above the list.
+    search: Option<TextInput<'a, Message, Renderer>>,
+    /// The query filtering the options.
+    query: String,
+    /// How the query is matched against the options.
+    search_mode: SearchMode,
+    /// Builds the content of the rows.
+    row_builder: Option<RowBuilder<'a, T, Message, Renderer>>,
+    /// Returns the key of the section of an option.
+    group_by: Option<GroupBy<'a, T>>,
+    /// The message to produce when an option is dragged to another position.
+    on_reorder: Option<OnReorder<Message>>,
+    /// Returns the key identifying an option.
+    key: Option<Key<'a, T>>,
 }
 
+/// A function returning the key of the section of an option.
+type GroupBy<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
 #[allow(clippy::type_repetition_in_bounds)]
 impl<'a, T, Message, Renderer> SelectionList<'a, T, Message, Renderer>
 where
     Message: 'a + Clone,
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
-    Renderer::Theme: StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
     T: Clone + Display + Eq + Hash,
     [T]: ToOwned<Owned = Vec<T>>,
 {
@@ -63,36 +117,26 @@ where
     /// the current selected value, and the `message` to produce when an option is
     /// selected. This will default the `style`, `text_size` and `padding`. use `new_with`
     /// to set those.
-    pub fn new(options: &'a [T], on_selected: impl Fn(usize, T) -> Message + 'static) -> Self {
-        let container = Container::new(Scrollable::new(List {
-            options,
-            font: Font::default(),
-            text_size: 12.0,
-            padding: 5.0,
-            style: <Renderer::Theme as StyleSheet>::Style::default(),
-            on_selected: Box::new(on_selected),
-            selected: None,
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
-
-        Self {
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        on_selected: impl Fn(usize, T) -> Message + 'static,
+    ) -> Self {
+        Self::new_with(
             options,
-            font: Font::default(),
-            style: <Renderer::Theme as StyleSheet>::Style::default(),
-            container,
-            width: Length::Fill,
-            height: Length::Fill,
-            padding: 5.0,
-            text_size: 12.0,
-        }
+            on_selected,
+            12.0,
+            5.0,
+            <Renderer::Theme as StyleSheet>::Style::default(),
+            None,
+            Font::default(),
+        )
     }
 
     /// Creates a new [`SelectionList`] with the given list of `options`,
     /// the current selected value, the message to produce when an option is
     /// selected, the `style`, `text_size`, `padding` and `font`.
     pub fn new_with(
-        options: &'a [T],
+        options: impl Into<Cow<'a, [T]>>,
         on_selected: impl Fn(usize, T) -> Message + 'static,
         text_size: f32,
         padding: f32,
@@ -100,31 +144,34 @@ where
         selected: Option<usize>,
         font: Font,
     ) -> Self {
-        let container = Container::new(Scrollable::new(List {
-            options,
-            font,
-            text_size,
-            padding,
-            style: style.clone(),
-            selected,
-            on_selected: Box::new(on_selected),
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
-
         Self {
-            options,
+            options: Rc::new(options.into()),
             font,
             style,
-            container,
+            container: OnceCell::new(),
             width: Length::Fill,
             height: Length::Fill,
             padding,
             text_size,
+            on_selected: Rc::new(on_selected),
+            on_selection_change: None,
+            selected,
+            selection: None,
+            search: None,
+            query: String::new(),
+            search_mode: SearchMode::default(),
+            row_builder: None,
+            group_by: None,
+            on_reorder: None,
+            key: None,
         }
     }
 
     /// Sets the width of the [`SelectionList`].
+    ///
+    /// Only the visible options are drawn, but a [`Length::Shrink`] width
+    /// measures the labels of all options. Prefer a fixed or filling width for
+    /// very large lists.
     #[must_use]
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -144,21 +191,216 @@ where
         self.style = style;
         self
     }
+
+    /// Allows selecting multiple options of the [`SelectionList`].
+    ///
+    /// Instead of the `on_selected` message, the given function produces a
+    /// message with all selected indices and options whenever the selection
+    /// changes.
+    #[must_use]
+    pub fn multi_select(
+        mut self,
+        on_selection_change: impl Fn(Vec<(usize, T)>) -> Message + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Rc::new(on_selection_change));
+        self
+    }
+
+    /// Sets the selected indices of a multi-select [`SelectionList`] manually.
+    #[must_use]
+    pub fn selection(mut self, selection: impl IntoIterator<Item = usize>) -> Self {
+        self.selection = Some(selection.into_iter().collect());
+        self
+    }
+
+    /// Shows a search field with the `placeholder` above the [`SelectionList`]
+    /// and only lists the options matching the `query`.
+    ///
+    /// The query is owned by the application: typing in the search field
+    /// produces the message of `on_search` with the new query. The indices
+    /// passed to `on_selected` are still those of the unfiltered options.
+    #[must_use]
+    pub fn search(
+        mut self,
+        placeholder: &str,
+        query: &str,
+        on_search: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.search = Some(
+            TextInput::new(placeholder, query)
+                .on_input(on_search)
+                .font(self.font)
+                .size(self.text_size)
+                .padding(self.padding),
+        );
+        query.clone_into(&mut self.query);
+        self
+    }
+
+    /// Sets how the search query is matched against the options of the
+    /// [`SelectionList`]. Defaults to [`SearchMode::Substring`].
+    #[must_use]
+    pub fn search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_mode = search_mode;
+        self
+    }
+
+    /// Builds the content of each row of the [`SelectionList`] with the given
+    /// function instead of drawing the options as text.
+    ///
+    /// The list still handles hovering, selecting and styling the rows: the
+    /// background is drawn behind the content and its text color is the one
+    /// of the row's [`RowState`]. Rows keep the height of a text row and are
+    /// clipped to it, so use [`new_with`](Self::new_with) to change
+    /// `text_size` and `padding` for taller content. Matched characters of a
+    /// search are not highlighted in custom rows.
+    ///
+    /// The content of the visible rows receives events before the list, so
+    /// it can contain interactive widgets like checkboxes and buttons, and an
+    /// event captured by the content does not select the row. The widget
+    /// state of the content is kept while its row is visible.
+    #[must_use]
+    pub fn row_builder(
+        mut self,
+        row_builder: impl Fn(&T, RowState) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        self.row_builder = Some(Rc::new(row_builder));
+        self
+    }
+
+    /// Groups the options of the [`SelectionList`] into sections with a
+    /// header showing the key returned by the given function, e.g. their first
+    /// letter or category.
+    ///
+    /// A new section starts whenever the key differs from the one of the
+    /// previous option, so the options should be sorted by their key. The
+    /// header of the section at the top sticks to the top of the list while
+    /// scrolling. Headers cannot be selected and are skipped by the keyboard
+    /// navigation.
+    #[must_use]
+    pub fn group_by(mut self, group_by: impl Fn(&T) -> String + 'a) -> Self {
+        self.group_by = Some(Box::new(group_by));
+        self
+    }
+
+    /// Lets the options of the [`SelectionList`] be dragged to another
+    /// position, producing the message of `on_reorder` with the index the
+    /// option was dragged from and the index it has to be moved to.
+    ///
+    /// The options are owned by the application, which has to move them,
+    /// e.g. with `let option = options.remove(from); options.insert(to, option);`.
+    /// A line shows where the option is dropped, and the list scrolls while it
+    /// is dragged near its top or bottom.
+    #[must_use]
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'static) -> Self {
+        self.on_reorder = Some(Rc::new(on_reorder));
+        self
+    }
+
+    /// Identifies the options of the [`SelectionList`] by the key returned by
+    /// the given function instead of their hash, e.g. the id of a record.
+    ///
+    /// The selected and hovered options are found again by their key whenever
+    /// the options change, so options with the same key should be equal.
+    #[must_use]
+    pub fn key<K: Hash>(mut self, key: impl Fn(&T) -> K + 'a) -> Self {
+        self.key = Some(Rc::new(move |option| {
+            let mut hasher = DefaultHasher::new();
+            key(option).hash(&mut hasher);
+            hasher.finish()
+        }));
+        self
+    }
+
+    /// Returns the rows of the [`List`] and the rows of their section
+    /// headers, if the options are filtered or grouped.
+    fn rows(&self) -> (Option<Vec<Entry>>, Vec<usize>) {
+        if self.query.is_empty() && self.group_by.is_none() {
+            return (None, Vec::new());
+        }
+
+        let mut rows = Vec::new();
+        let mut headers = Vec::new();
+        let mut section = None;
+
+        for (index, option) in self.options.iter().enumerate() {
+            let matched = if self.query.is_empty() {
+                Vec::new()
+            } else if let Some(matched) = self.search_mode.find(&option.to_string(), &self.query) {
+                matched
+            } else {
+                continue;
+            };
+
+            if let Some(group_by) = &self.group_by {
+                let key = group_by(option);
+                if section.as_ref() != Some(&key) {
+                    headers.push(rows.len());
+                    rows.push(Entry::Header(key.clone()));
+                    section = Some(key);
+                }
+            }
+
+            rows.push(Entry::Option { index, matched });
+        }
+
+        (Some(rows), headers)
+    }
+
+    /// Builds the scrollable [`List`] from the settings, filtering and
+    /// grouping the options once per view.
+    fn build(&self) -> Container<'a, Message, Renderer> {
+        let (rows, headers) = self.rows();
+
+        Container::new(Scrollable::new(List {
+            options: Rc::clone(&self.options),
+            font: self.font,
+            text_size: self.text_size,
+            padding: self.padding,
+            style: self.style.clone(),
+            selected: self.selected,
+            selection: self.selection.clone(),
+            on_selected: Rc::clone(&self.on_selected),
+            on_selection_change: self.on_selection_change.clone(),
+            rows,
+            headers,
+            row_builder: self.row_builder.clone(),
+            contents: RefCell::default(),
+            on_reorder: self.on_reorder.clone(),
+            key: self.key.clone(),
+            phantomdata: PhantomData,
+        }))
+        .padding(1)
+    }
+
+    /// Returns the container of the [`List`], building it if needed.
+    fn container(&self) -> &Container<'a, Message, Renderer> {
+        self.container.get_or_init(|| self.build())
+    }
+
+    /// Returns the mutable container of the [`List`], building it if needed.
+    fn container_mut(&mut self) -> &mut Container<'a, Message, Renderer> {
+        let _ = self.container();
+        self.container
+            .get_mut()
+            .expect("Native: the list of the selection list should be built")
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for SelectionList<'a, T, Message, Renderer>
 where
-    T: 'a + Clone + ToString + Eq + Hash,
-    Message: 'static,
+    T: 'a + Clone + Display + Eq + Hash,
+    Message: 'static + Clone,
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font> + 'a,
-    Renderer::Theme: StyleSheet + container::StyleSheet,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
 {
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.container as &dyn Widget<_, _>)]
+        self.widgets().into_iter().map(Tree::new).collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.container as &dyn Widget<_, _>]);
+        tree.diff_children(&self.widgets());
     }
 
     fn width(&self) -> Length {
@@ -199,9 +441,22 @@ where
 
         let limits = limits.max_width(max_width as f32 + self.padding * 2.0);
 
-        let content = self.container.layout(renderer, &limits);
-        let size = limits.resolve(content.size());
-        Node::with_children(size, vec![content])
+        let search = self
+            .search
+            .as_ref()
+            .map(|search| search.layout(renderer, &limits));
+        let search_height = search.as_ref().map_or(0.0, |search| search.size().height);
+
+        let mut content = self
+            .container()
+            .layout(renderer, &limits.shrink(Size::new(0.0, search_height)));
+        content.move_to(Point::new(0.0, search_height));
+
+        let size = limits.resolve(Size::new(
+            content.size().width,
+            content.size().height + search_height,
+        ));
+        Node::with_children(size, std::iter::once(content).chain(search).collect())
     }
 
     fn on_event(
@@ -215,7 +470,26 @@ where
         shell: &mut Shell<Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        self.container.on_event(
+        if let Some(search) = &mut self.search {
+            let status = search.on_event(
+                &mut state.children[1],
+                event.clone(),
+                layout
+                    .children()
+                    .nth(1)
+                    .expect("Search Child Missing in Selection List"),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let status = self.container_mut().on_event(
             &mut state.children[0],
             event,
             layout
@@ -227,7 +501,49 @@ where
             clipboard,
             shell,
             viewport,
-        )
+        );
+
+        // Scroll the option hovered with the keyboard into view.
+        let scrollable = &mut state.children[0].children[0];
+        let list_state = scrollable.children[0].state.downcast_mut::<ListState>();
+        if let Some(offset) = list_state.scroll_to.take() {
+            scrollable
+                .state
+                .downcast_mut::<scrollable::State>()
+                .scroll_to(AbsoluteOffset { x: 0.0, y: offset });
+        }
+
+        status
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.container().operate(
+            &mut state.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Scrollable Child Missing in Selection List"),
+            renderer,
+            operation,
+        );
+
+        if let Some(search) = &self.search {
+            search.operate(
+                &mut state.children[1],
+                layout
+                    .children()
+                    .nth(1)
+                    .expect("Search Child Missing in Selection List"),
+                renderer,
+                operation,
+            );
+        }
     }
 
     fn mouse_interaction(
@@ -238,8 +554,27 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.container
-            .mouse_interaction(&state.children[0], layout, cursor, viewport, renderer)
+        let mut children = layout.children();
+        let content = self.container().mouse_interaction(
+            &state.children[0],
+            children
+                .next()
+                .expect("Scrollable Child Missing in Selection List"),
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        match (&self.search, children.next()) {
+            (Some(search), Some(layout)) => content.max(search.mouse_interaction(
+                &state.children[1],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )),
+            _ => content,
+        }
     }
 
     fn draw(
@@ -262,7 +597,7 @@ where
             theme.style(&self.style).background,
         );
 
-        self.container.draw(
+        self.container().draw(
             &state.children[0],
             renderer,
             theme,
@@ -274,16 +609,49 @@ where
             cursor,
             &layout.bounds(),
         );
+
+        if let (Some(search), Some(search_layout)) = (&self.search, layout.children().nth(1)) {
+            Widget::<Message, Renderer>::draw(
+                search,
+                &state.children[1],
+                renderer,
+                theme,
+                style,
+                search_layout,
+                cursor,
+                &layout.bounds(),
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> SelectionList<'a, T, Message, Renderer>
+where
+    T: 'a + Clone + Display + Eq + Hash,
+    Message: 'a + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
+{
+    /// Returns the list and, if shown, the search field.
+    fn widgets(&self) -> Vec<&dyn Widget<Message, Renderer>> {
+        let mut widgets: Vec<&dyn Widget<Message, Renderer>> = vec![self.container()];
+        if let Some(search) = &self.search {
+            widgets.push(search);
+        }
+        widgets
     }
 }
 
 impl<'a, T, Message, Renderer> From<SelectionList<'a, T, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    T: Clone + ToString + Eq + Hash,
-    Message: 'static,
+    T: 'a + Clone + Display + Eq + Hash,
+    Message: 'static + Clone,
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
-    Renderer::Theme: StyleSheet + container::StyleSheet,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
 {
     fn from(selection_list: SelectionList<'a, T, Message, Renderer>) -> Self {
         Element::new(selection_list)
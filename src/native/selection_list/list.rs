@@ -1,29 +1,114 @@
 //! Build and show dropdown `ListMenus`.
 use std::{
-    collections::hash_map::DefaultHasher,
+    borrow::Cow,
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
     fmt::Display,
     hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::Range,
+    rc::Rc,
+    time::{Duration, Instant},
 };
 
-use crate::selection_list::StyleSheet;
+use crate::style::selection_list::{Appearance, StyleSheet};
 
 use iced_widget::{
     core::{
         self,
         alignment::{Horizontal, Vertical},
-        event, layout,
+        event, keyboard, layout,
         mouse::{self, Cursor},
         renderer, touch,
         widget::{
+            operation,
             tree::{State, Tag},
-            Tree,
+            Operation, Tree,
         },
-        Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+        window, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+        Widget,
     },
     text::LineHeight,
 };
 
+/// The time after which a typed character starts a new type-ahead prefix.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+/// The distance the cursor has to move with a pressed option before it is
+/// dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The thickness of the line showing where a dragged option is dropped.
+const DROP_INDICATOR_HEIGHT: f32 = 2.0;
+
+/// A callback producing a message for the selected index and option.
+pub(crate) type OnSelected<T, Message> = Rc<dyn Fn(usize, T) -> Message>;
+/// A callback producing a message for all selected indices and options.
+pub(crate) type OnSelectionChange<T, Message> = Rc<dyn Fn(Vec<(usize, T)>) -> Message>;
+/// A function building the content of a row from its option and [`RowState`].
+pub(crate) type RowBuilder<'a, T, Message, Renderer> =
+    Rc<dyn Fn(&T, RowState) -> Element<'a, Message, Renderer> + 'a>;
+/// A function returning the key identifying an option across changes of the
+/// options.
+pub(crate) type Key<'a, T> = Rc<dyn Fn(&T) -> u64 + 'a>;
+/// A callback producing a message for an option dragged from one index to
+/// another.
+pub(crate) type OnReorder<Message> = Rc<dyn Fn(usize, usize) -> Message>;
+
+/// A row of a [`List`] showing a subset of its options.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Entry {
+    /// A header starting a section, which cannot be selected.
+    Header(String),
+    /// An option of the list.
+    Option {
+        /// The index of the option in the list of options.
+        index: usize,
+        /// The positions of the characters of the option matching the search
+        /// query.
+        matched: Vec<usize>,
+    },
+}
+
+/// The state of a row passed to the row builder of a
+/// [`SelectionList`](crate::SelectionList).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RowState {
+    /// The index of the option in the list of options.
+    pub index: usize,
+    /// Whether the row is hovered.
+    pub is_hovered: bool,
+    /// Whether the option is selected.
+    pub is_selected: bool,
+}
+
+/// The custom content of a row of a [`List`], built by its row builder.
+#[allow(missing_debug_implementations)]
+pub struct RowContent<'a, Message, Renderer> {
+    /// The row showing the option.
+    pub row: usize,
+    /// The key of the option.
+    pub key: u64,
+    /// The [`RowState`] the content was built for.
+    pub row_state: RowState,
+    /// The content built by the row builder.
+    pub element: Element<'a, Message, Renderer>,
+    /// The size of the row and the layout of the content in it, once laid out.
+    pub node: Option<(Size, layout::Node)>,
+}
+
+/// An option of a [`List`] being dragged to another position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Drag {
+    /// The index of the dragged option in the list of options.
+    pub from: usize,
+    /// The vertical position the option was pressed at.
+    pub origin: f32,
+    /// The vertical position of the cursor relative to the viewport.
+    pub pointer: f32,
+    /// The gap between the rows the option is dropped into, once the cursor
+    /// moved far enough to start dragging.
+    pub gap: Option<usize>,
+}
+
 /// The Private [`List`] Handles the Actual list rendering.
 #[allow(missing_debug_implementations)]
 pub struct List<'a, T: 'a, Message, Renderer>
@@ -33,21 +118,40 @@ where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet,
 {
-    /// Options pointer to hold all rendered strings
-    pub options: &'a [T],
+    /// The borrowed or owned options, shared with the
+    /// [`SelectionList`](crate::SelectionList).
+    pub options: Rc<Cow<'a, [T]>>,
     /// Hovered Item Pointer
     /// Label Font
     pub font: Renderer::Font,
     /// Style for Font colors and Box hover colors.
     pub style: <Renderer::Theme as StyleSheet>::Style,
     /// Function Pointer On Select to call on Mouse button press.
-    pub on_selected: Box<dyn Fn(usize, T) -> Message>,
+    pub on_selected: OnSelected<T, Message>,
+    /// Function Pointer to call with all selected options of a multi-select list.
+    pub on_selection_change: Option<OnSelectionChange<T, Message>>,
     /// The padding Width
     pub padding: f32,
     /// The Text Size
     pub text_size: f32,
     /// Set the Selected ID manually.
     pub selected: Option<usize>,
+    /// Set the selected IDs of a multi-select list manually.
+    pub selection: Option<Vec<usize>>,
+    /// The rows shown instead of one row per option, if the options are
+    /// filtered by a search query or grouped into sections.
+    pub rows: Option<Vec<Entry>>,
+    /// The rows of the section headers, in ascending order.
+    pub headers: Vec<usize>,
+    /// Builds the content of the rows instead of drawing the options as text.
+    pub row_builder: Option<RowBuilder<'a, T, Message, Renderer>>,
+    /// The content built by the row builder for the rows whose widget state
+    /// is kept, in the same order as the children of the tree.
+    pub contents: RefCell<Vec<RowContent<'a, Message, Renderer>>>,
+    /// Function Pointer to call when an option is dragged to another position.
+    pub on_reorder: Option<OnReorder<Message>>,
+    /// Returns the key of an option instead of its hash.
+    pub key: Option<Key<'a, T>>,
     /// Shadow Type holder for Renderer.
     pub phantomdata: PhantomData<Renderer>,
 }
@@ -55,10 +159,765 @@ where
 /// The Private [`ListState`] Handles the State of the inner list.
 #[derive(Debug, Clone, Default)]
 pub struct ListState {
-    /// Statehood of hovered_option
+    /// The shown row of the hovered option
     pub hovered_option: Option<usize>,
-    /// The index in the list of options of the last chosen Item Clicked for Processing
+    /// The index and key of the hovered option, which is hovered again after
+    /// the options changed.
+    pub hovered_key: Option<(usize, u64)>,
+    /// The index in the list of options of the last chosen Item Clicked for Processing,
+    /// with the key of the option.
     pub last_selected_index: Option<(usize, u64)>,
+    /// The indices of the selected options of a multi-select list, with the
+    /// keys of the options.
+    pub selection: BTreeMap<usize, u64>,
+    /// The index and key of the option a shift-click selects the range from.
+    pub anchor: Option<(usize, u64)>,
+    /// The pressed keyboard modifiers.
+    pub modifiers: keyboard::Modifiers,
+    /// Whether the list has the keyboard focus.
+    pub is_focused: bool,
+    /// The typed prefix of the option to jump to.
+    pub type_ahead: String,
+    /// The point in time the last character of the prefix was typed.
+    pub last_typed: Option<Instant>,
+    /// The vertical offset the wrapping scrollable has to scroll to, so that
+    /// the hovered option is visible.
+    pub scroll_to: Option<f32>,
+    /// The pressed option, which is dragged once the cursor moves.
+    pub drag: Option<Drag>,
+    /// The row, index and key of the options whose custom content has its
+    /// widget state in the children of the tree of the [`List`], in the same
+    /// order.
+    pub content_rows: Vec<(usize, usize, u64)>,
+}
+
+impl operation::Focusable for ListState {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, T, Message, Renderer> List<'a, T, Message, Renderer>
+where
+    T: Clone + Display + Eq + Hash,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the height of a single option.
+    fn option_height(&self) -> f32 {
+        self.text_size + self.padding * 2.0
+    }
+
+    /// Returns the number of shown rows.
+    fn row_count(&self) -> usize {
+        self.rows.as_ref().map_or(self.options.len(), Vec::len)
+    }
+
+    /// Returns the key identifying the option across changes of the options.
+    fn key(&self, option: &T) -> u64 {
+        self.key.as_ref().map_or_else(
+            || {
+                let mut hasher = DefaultHasher::new();
+                option.hash(&mut hasher);
+                hasher.finish()
+            },
+            |key| key(option),
+        )
+    }
+
+    /// Returns the index of the option with the key, which was at the given
+    /// index before the options changed.
+    ///
+    /// The options are only searched if the option at the index changed. The
+    /// index of the first option of each key is then looked up once per diff.
+    fn locate(
+        &self,
+        index: usize,
+        key: u64,
+        lookup: &mut Option<HashMap<u64, usize>>,
+    ) -> Option<usize> {
+        if self
+            .options
+            .get(index)
+            .is_some_and(|option| self.key(option) == key)
+        {
+            return Some(index);
+        }
+
+        lookup
+            .get_or_insert_with(|| {
+                let mut lookup = HashMap::with_capacity(self.options.len());
+                for (index, option) in self.options.iter().enumerate() {
+                    let _ = lookup.entry(self.key(option)).or_insert(index);
+                }
+                lookup
+            })
+            .get(&key)
+            .copied()
+    }
+
+    /// Hovers the row and remembers the key of its option.
+    fn set_hovered(&self, list_state: &mut ListState, row: Option<usize>) {
+        list_state.hovered_option = row;
+        list_state.hovered_key = row
+            .and_then(|row| self.option_index(row))
+            .and_then(|index| {
+                self.options
+                    .get(index)
+                    .map(|option| (index, self.key(option)))
+            });
+    }
+
+    /// Returns the rows intersecting the viewport, so that only those have to
+    /// be drawn no matter how many options there are.
+    fn visible_rows(&self, bounds: Rectangle, viewport: &Rectangle) -> Range<usize> {
+        let option_height = self.option_height();
+        let top = (viewport.y - bounds.y).max(0.0);
+        let bottom = (viewport.y + viewport.height - bounds.y).max(0.0);
+        let count = self.row_count();
+
+        ((top / option_height) as usize).min(count)
+            ..((bottom / option_height).ceil() as usize).min(count)
+    }
+
+    /// Returns the bounds of the row.
+    fn row_bounds(&self, bounds: Rectangle, row: usize) -> Rectangle {
+        Rectangle {
+            y: bounds.y + self.option_height() * row as f32,
+            height: self.option_height(),
+            ..bounds
+        }
+    }
+
+    /// Returns the [`RowState`] of the row showing the option at the index.
+    fn row_state(&self, list_state: &ListState, row: usize, index: usize) -> RowState {
+        let is_selected = if self.on_selection_change.is_some() {
+            list_state.selection.contains_key(&index)
+        } else {
+            list_state
+                .last_selected_index
+                .is_some_and(|(selected, _)| selected == index)
+        };
+
+        RowState {
+            index,
+            is_hovered: list_state.hovered_option == Some(row),
+            is_selected,
+        }
+    }
+
+    /// Builds the custom content of the row with the row builder and returns
+    /// it with the index of its option.
+    fn row_content(
+        &self,
+        list_state: &ListState,
+        row: usize,
+    ) -> Option<(usize, RowContent<'a, Message, Renderer>)> {
+        let row_builder = self.row_builder.as_ref()?;
+        let index = self.option_index(row)?;
+        let option = self.options.get(index)?;
+        let row_state = self.row_state(list_state, row, index);
+
+        Some((
+            index,
+            RowContent {
+                row,
+                key: self.key(option),
+                row_state,
+                element: row_builder(option, row_state),
+                node: None,
+            },
+        ))
+    }
+
+    /// Lays out the custom content of a row, centered vertically in the
+    /// bounds of the row. The layout is kept for rows of the same size.
+    fn row_layout(
+        renderer: &Renderer,
+        content: &mut RowContent<'a, Message, Renderer>,
+        bounds: Rectangle,
+    ) -> layout::Node {
+        let mut node = match &content.node {
+            Some((size, node)) if *size == bounds.size() => node.clone(),
+            _ => {
+                let limits = layout::Limits::new(Size::ZERO, bounds.size());
+                let node = content.element.as_widget().layout(renderer, &limits);
+                content.node = Some((bounds.size(), node.clone()));
+                node
+            }
+        };
+        node.move_to(Point::new(
+            bounds.x,
+            bounds.y + (bounds.height - node.size().height).max(0.0) / 2.0,
+        ));
+        node
+    }
+
+    /// Keeps the widget states of the custom content of the given rows in the
+    /// children of the tree and the content in the contents of the list. The
+    /// content is built again only if the [`RowState`] of its row changed.
+    /// States are found again by the key of their option and dropped once
+    /// their row is no longer given.
+    fn sync_rows(&self, state: &mut Tree, rows: impl IntoIterator<Item = usize>) {
+        let list_state = state.state.downcast_mut::<ListState>();
+        let mut previous: HashMap<u64, Tree> = std::mem::take(&mut list_state.content_rows)
+            .into_iter()
+            .map(|(_, _, key)| key)
+            .zip(std::mem::take(&mut state.children))
+            .collect();
+        let mut built: HashMap<u64, RowContent<'a, Message, Renderer>> =
+            std::mem::take(&mut *self.contents.borrow_mut())
+                .into_iter()
+                .map(|content| (content.key, content))
+                .collect();
+        let mut contents = Vec::new();
+
+        for row in rows {
+            let Some(index) = self.option_index(row) else {
+                continue;
+            };
+            let kept = self.options.get(index).and_then(|option| {
+                built.remove(&self.key(option)).filter(|content| {
+                    content.row == row
+                        && content.row_state == self.row_state(list_state, row, index)
+                })
+            });
+            let (content, tree) = if let Some(content) = kept {
+                let tree = previous
+                    .remove(&content.key)
+                    .unwrap_or_else(|| Tree::new(&content.element));
+                (content, tree)
+            } else {
+                let Some((_, content)) = self.row_content(list_state, row) else {
+                    continue;
+                };
+                let mut tree = previous
+                    .remove(&content.key)
+                    .unwrap_or_else(|| Tree::new(&content.element));
+                tree.diff(&content.element);
+                (content, tree)
+            };
+
+            list_state.content_rows.push((row, index, content.key));
+            state.children.push(tree);
+            contents.push(content);
+        }
+
+        *self.contents.borrow_mut() = contents;
+    }
+
+    /// Passes the event to the custom content of the visible rows.
+    #[allow(clippy::too_many_arguments)]
+    fn update_rows(
+        &self,
+        state: &mut Tree,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut contents = self.contents.borrow_mut();
+
+        for (content, tree) in contents.iter_mut().zip(&mut state.children) {
+            let node = Self::row_layout(renderer, content, self.row_bounds(bounds, content.row));
+
+            let status = content.element.as_widget_mut().on_event(
+                tree,
+                event.clone(),
+                Layout::new(&node),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Draws the custom content of a row, clipped to the bounds of the row.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_row(
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        tree: &Tree,
+        content: &mut RowContent<'a, Message, Renderer>,
+        bounds: Rectangle,
+        text_color: Color,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let node = Self::row_layout(renderer, content, bounds);
+
+        renderer.with_layer(bounds, |renderer| {
+            content.element.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style { text_color },
+                Layout::new(&node),
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    /// Returns the index of the option shown in the row.
+    fn option_index(&self, row: usize) -> Option<usize> {
+        self.rows.as_ref().map_or_else(
+            || (row < self.options.len()).then_some(row),
+            |rows| match rows.get(row) {
+                Some(Entry::Option { index, .. }) => Some(*index),
+                _ => None,
+            },
+        )
+    }
+
+    /// Returns the nearest row showing an option, looking in the given
+    /// direction first, so that headers are skipped.
+    fn option_row(&self, row: usize, forward: bool) -> Option<usize> {
+        let count = self.row_count();
+        let is_option = |row: &usize| self.option_index(*row).is_some();
+        let mut after = (row..count).filter(is_option);
+        let mut before = (0..(row + 1).min(count)).rev().filter(is_option);
+
+        if forward {
+            after.next().or_else(|| before.next())
+        } else {
+            before.next().or_else(|| after.next())
+        }
+    }
+
+    /// Returns the height of the section header sticking to the top of the
+    /// viewport, which hides the rows below it.
+    fn sticky_height(&self) -> f32 {
+        if self.headers.is_empty() {
+            0.0
+        } else {
+            self.option_height()
+        }
+    }
+
+    /// Returns the title and vertical position of the header of the section
+    /// at the top of the viewport, which is pushed up by the next header.
+    fn sticky_header(&self, bounds: Rectangle, viewport: &Rectangle) -> Option<(&str, f32)> {
+        let height = self.option_height();
+        let top = (viewport.y - bounds.y).max(0.0);
+        let current = self
+            .headers
+            .partition_point(|&row| row as f32 * height <= top);
+        let header = *self.headers.get(current.checked_sub(1)?)?;
+
+        let Some(Entry::Header(title)) = self.rows.as_ref()?.get(header) else {
+            return None;
+        };
+
+        let y = self
+            .headers
+            .get(current)
+            .map_or(top, |&next| top.min(next as f32 * height - height));
+
+        Some((title, bounds.y + y))
+    }
+
+    /// Draws a section header.
+    fn draw_header(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        title: &str,
+        bounds: Rectangle,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.header_background,
+        );
+
+        renderer.fill_text(core::text::Text {
+            content: title,
+            bounds: Rectangle {
+                x: bounds.x,
+                y: bounds.center_y(),
+                width: f32::INFINITY,
+                ..bounds
+            },
+            size: self.text_size,
+            color: appearance.header_text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            line_height: LineHeight::default(),
+            shaping: iced_widget::text::Shaping::Advanced,
+        });
+    }
+
+    /// Returns the row showing the option at the index, if it is shown.
+    fn row(&self, index: usize) -> Option<usize> {
+        self.rows.as_ref().map_or_else(
+            || (index < self.options.len()).then_some(index),
+            |rows| {
+                rows.iter().position(
+                    |entry| matches!(entry, Entry::Option { index: i, .. } if *i == index),
+                )
+            },
+        )
+    }
+
+    /// Selects the option shown in the row, like clicking on it does.
+    fn select(
+        &self,
+        list_state: &mut ListState,
+        row: usize,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let Some((index, option)) = self
+            .option_index(row)
+            .and_then(|index| self.options.get(index).map(|option| (index, option)))
+        else {
+            return event::Status::Ignored;
+        };
+
+        if let Some(on_selection_change) = &self.on_selection_change {
+            let selection = self.select_multiple(list_state, index);
+            shell.publish(on_selection_change(selection));
+        } else {
+            list_state.last_selected_index = Some((index, self.key(option)));
+
+            shell.publish((self.on_selected)(index, option.clone()));
+        }
+
+        event::Status::Captured
+    }
+
+    /// Hovers the option shown in the row and scrolls it into view.
+    fn hover(
+        &self,
+        list_state: &mut ListState,
+        row: usize,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let height = self.option_height();
+        let top = height * row as f32;
+        let offset = viewport.y - bounds.y + self.sticky_height();
+
+        self.set_hovered(list_state, Some(row));
+        list_state.scroll_to = if top < offset {
+            Some((top - self.sticky_height()).max(0.0))
+        } else if top + height > offset + viewport.height {
+            Some(top + height - viewport.height)
+        } else {
+            None
+        };
+    }
+
+    /// Moves the hovered option with the arrow, page and home/end keys and
+    /// selects it with the enter key.
+    pub(crate) fn navigate(
+        &self,
+        list_state: &mut ListState,
+        key_code: keyboard::KeyCode,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let Some(last) = self.row_count().checked_sub(1) else {
+            return event::Status::Ignored;
+        };
+
+        let current = list_state
+            .hovered_option
+            .or_else(|| {
+                list_state
+                    .last_selected_index
+                    .and_then(|(index, _)| self.row(index))
+            })
+            .or_else(|| list_state.anchor.and_then(|(index, _)| self.row(index)))
+            .map(|row| row.min(last));
+        let page = ((viewport.height / self.option_height()).floor() as usize).max(1);
+
+        let (row, forward) = match key_code {
+            keyboard::KeyCode::Up => (current.map_or(0, |row| row.saturating_sub(1)), false),
+            keyboard::KeyCode::Down => (current.map_or(0, |row| (row + 1).min(last)), true),
+            keyboard::KeyCode::PageUp => (current.map_or(0, |row| row.saturating_sub(page)), false),
+            keyboard::KeyCode::PageDown => (current.map_or(0, |row| (row + page).min(last)), true),
+            keyboard::KeyCode::Home => (0, true),
+            keyboard::KeyCode::End => (last, false),
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                return current.map_or(event::Status::Ignored, |row| {
+                    self.select(list_state, row, shell)
+                });
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        if let Some(row) = self.option_row(row, forward) {
+            self.hover(list_state, row, bounds, viewport);
+        }
+        event::Status::Captured
+    }
+
+    /// Adds the character to the type-ahead prefix and hovers the first option
+    /// starting with it.
+    fn type_ahead(
+        &self,
+        list_state: &mut ListState,
+        c: char,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let now = Instant::now();
+        let is_continued = list_state
+            .last_typed
+            .is_some_and(|last_typed| now - last_typed <= TYPE_AHEAD_TIMEOUT);
+        if !is_continued {
+            list_state.type_ahead.clear();
+        }
+        list_state.last_typed = Some(now);
+        list_state.type_ahead.extend(c.to_lowercase());
+
+        let row = (0..self.row_count()).find(|&row| {
+            self.option_index(row)
+                .and_then(|index| self.options.get(index))
+                .is_some_and(|option| {
+                    option
+                        .to_string()
+                        .to_lowercase()
+                        .starts_with(&list_state.type_ahead)
+                })
+        });
+        if let Some(row) = row {
+            self.hover(list_state, row, bounds, viewport);
+        }
+    }
+
+    /// Returns the gap between the rows nearest to the vertical position.
+    fn gap(&self, bounds: Rectangle, y: f32) -> usize {
+        (((y - bounds.y) / self.option_height()).round().max(0.0) as usize).min(self.row_count())
+    }
+
+    /// Returns the index the option at `from` has to be moved to, so that it
+    /// ends up in the gap, or [`None`] if it stays where it is.
+    fn reorder_target(&self, from: usize, gap: usize) -> Option<usize> {
+        let insert = (gap..self.row_count())
+            .find_map(|row| self.option_index(row))
+            .or_else(|| {
+                (0..gap)
+                    .rev()
+                    .find_map(|row| self.option_index(row))
+                    .map(|index| index + 1)
+            })?;
+        let to = if insert > from { insert - 1 } else { insert };
+
+        (to != from).then_some(to)
+    }
+
+    /// Moves the drop position of the dragged option with the cursor and
+    /// scrolls while the cursor is near the top or bottom of the viewport.
+    fn drag(
+        &self,
+        list_state: &mut ListState,
+        event: &Event,
+        cursor: Cursor,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let Some(drag) = list_state.drag.as_mut() else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                // The cursor is unavailable outside of the scrollable, where
+                // the last position keeps scrolling.
+                if let Some(position) = cursor.position() {
+                    drag.pointer = position.y - viewport.y;
+                    if drag.gap.is_none() && (position.y - drag.origin).abs() < DRAG_THRESHOLD {
+                        return event::Status::Ignored;
+                    }
+                }
+                if drag.gap.is_none() {
+                    list_state.hovered_option = None;
+                    list_state.hovered_key = None;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+                drag.gap = Some(self.gap(bounds, viewport.y + drag.pointer));
+                event::Status::Captured
+            }
+            Event::Window(window::Event::RedrawRequested(_)) if drag.gap.is_some() => {
+                let edge = self.option_height();
+                let offset = viewport.y - bounds.y;
+                let max = (bounds.height - viewport.height).max(0.0);
+                let step = if drag.pointer < edge + self.sticky_height() {
+                    -edge / 2.0
+                } else if drag.pointer > viewport.height - edge {
+                    edge / 2.0
+                } else {
+                    0.0
+                };
+
+                let scrolled = (offset + step).clamp(0.0, max);
+                if (scrolled - offset).abs() > f32::EPSILON {
+                    list_state.scroll_to = Some(scrolled);
+                    drag.gap = Some(self.gap(bounds, bounds.y + scrolled + drag.pointer));
+                }
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Drag { from, gap, .. } = *drag;
+                list_state.drag = None;
+
+                let Some(gap) = gap else {
+                    return event::Status::Ignored;
+                };
+                if let (Some(on_reorder), Some(to)) =
+                    (&self.on_reorder, self.reorder_target(from, gap))
+                {
+                    shell.publish(on_reorder(from, to));
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Handles an event for hovering, selecting, navigating and dragging the
+    /// options.
+    fn update(
+        &self,
+        list_state: &mut ListState,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+        shell: &mut Shell<Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if self.drag(list_state, event, cursor, bounds, viewport, shell) == event::Status::Captured
+        {
+            return event::Status::Captured;
+        }
+        let cursor = cursor.position().unwrap_or_default();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                list_state.modifiers = *modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                list_state.is_focused = bounds.contains(cursor);
+                list_state.type_ahead.clear();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if list_state.is_focused =>
+            {
+                return self.navigate(list_state, *key_code, bounds, viewport, shell);
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if list_state.is_focused && !c.is_control() =>
+            {
+                self.type_ahead(list_state, *c, bounds, viewport);
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let is_under_header = self
+            .sticky_header(bounds, viewport)
+            .is_some_and(|(_, y)| cursor.y < y + self.option_height());
+
+        if bounds.contains(cursor) && !is_under_header {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    let row = ((cursor.y - bounds.y) / self.option_height()) as usize;
+                    self.set_hovered(list_state, self.option_index(row).map(|_| row));
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    let index = ((cursor.y - bounds.y) / self.option_height()) as usize;
+                    self.set_hovered(list_state, Some(index));
+
+                    if self.on_reorder.is_some() {
+                        list_state.drag = self.option_index(index).map(|from| Drag {
+                            from,
+                            origin: cursor.y,
+                            pointer: cursor.y - viewport.y,
+                            gap: None,
+                        });
+                    }
+
+                    return self.select(list_state, index, shell);
+                }
+                _ => {}
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Updates the selection of a multi-select list after the option at the
+    /// index was clicked and returns all selected indices and options.
+    fn select_multiple(&self, list_state: &mut ListState, index: usize) -> Vec<(usize, T)> {
+        let modifiers = list_state.modifiers;
+
+        let anchor_row = list_state
+            .anchor
+            .filter(|_| modifiers.shift())
+            .and_then(|(anchor, _)| self.row(anchor));
+        let row = self.row(index);
+        let keyed = |index: usize| {
+            self.options
+                .get(index)
+                .map(|option| (index, self.key(option)))
+        };
+
+        if let (Some(anchor_row), Some(row)) = (anchor_row, row) {
+            if !modifiers.command() {
+                list_state.selection.clear();
+            }
+            list_state.selection.extend(
+                (anchor_row.min(row)..=anchor_row.max(row))
+                    .filter_map(|row| self.option_index(row))
+                    .filter_map(keyed),
+            );
+        } else {
+            if !modifiers.command() {
+                list_state.selection = keyed(index).into_iter().collect();
+            } else if list_state.selection.remove(&index).is_none() {
+                list_state.selection.extend(keyed(index));
+            }
+            list_state.anchor = keyed(index);
+        }
+
+        list_state
+            .selection
+            .keys()
+            .filter_map(|&i| self.options.get(i).map(|option| (i, option.clone())))
+            .collect()
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for List<'a, T, Message, Renderer>
@@ -77,28 +936,48 @@ where
 
     fn diff(&self, state: &mut Tree) {
         let list_state = state.state.downcast_mut::<ListState>();
+        let keyed = |index: usize| {
+            self.options
+                .get(index)
+                .map(|option| (index, self.key(option)))
+        };
+        // Options inserted, removed or sorted move the selected and hovered
+        // options to other indices, where they are found again by their key.
+        let mut lookup = None;
+        let mut locate = |(index, key): (usize, u64)| {
+            self.locate(index, key, &mut lookup)
+                .map(|index| (index, key))
+        };
 
-        if let Some(id) = self.selected {
-            if let Some(option) = self.options.get(id) {
-                let mut hasher = DefaultHasher::new();
-                option.hash(&mut hasher);
+        list_state.selection = if let Some(selection) = &self.selection {
+            selection.iter().filter_map(|&i| keyed(i)).collect()
+        } else {
+            std::mem::take(&mut list_state.selection)
+                .into_iter()
+                .filter_map(&mut locate)
+                .collect()
+        };
+        list_state.anchor = list_state.anchor.and_then(&mut locate);
 
-                list_state.last_selected_index = Some((id, hasher.finish()));
-            } else {
-                list_state.last_selected_index = None;
-            }
-        } else if let Some((id, hash)) = list_state.last_selected_index {
-            if let Some(option) = self.options.get(id) {
-                let mut hasher = DefaultHasher::new();
-                option.hash(&mut hasher);
+        list_state.last_selected_index = if let Some(id) = self.selected {
+            keyed(id)
+        } else {
+            list_state.last_selected_index.and_then(&mut locate)
+        };
 
-                if hash != hasher.finish() {
-                    list_state.last_selected_index = None;
-                }
-            } else {
-                list_state.last_selected_index = None;
-            }
+        if let Some(hovered) = list_state.hovered_key {
+            list_state.hovered_key = locate(hovered);
+            list_state.hovered_option = list_state
+                .hovered_key
+                .and_then(|(index, _)| self.row(index));
         }
+
+        let rows: Vec<usize> = list_state
+            .content_rows
+            .iter()
+            .map(|&(row, _, _)| row)
+            .collect();
+        self.sync_rows(state, rows);
     }
 
     fn width(&self) -> Length {
@@ -116,82 +995,118 @@ where
         #[allow(clippy::cast_precision_loss)]
         let intrinsic = Size::new(
             limits.fill().width,
-            (self.text_size + self.padding * 2.0) * self.options.len() as f32,
+            self.option_height() * self.row_count() as f32,
         );
 
         layout::Node::new(intrinsic)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<ListState>(), None);
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
-        let bounds = layout.bounds();
-        let mut status = event::Status::Ignored;
-        let list_state = state.state.downcast_mut::<ListState>();
-        let cursor = cursor.position().unwrap_or_default();
-
-        if bounds.contains(cursor) {
-            match event {
-                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
-                }
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
+        if self.row_builder.is_none() {
+            return self.update(
+                state.state.downcast_mut::<ListState>(),
+                &event,
+                layout.bounds(),
+                cursor,
+                shell,
+                viewport,
+            );
+        }
 
-                    if let Some(index) = list_state.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            let mut hasher = DefaultHasher::new();
-                            option.hash(&mut hasher);
-                            list_state.last_selected_index = Some((index, hasher.finish()));
-                        }
-                    }
+        // The content of the visible rows gets the events first, e.g. so that
+        // clicking a checkbox does not select the row.
+        let bounds = layout.bounds();
+        let rows = self.visible_rows(bounds, viewport);
+        self.sync_rows(state, rows.clone());
 
-                    status =
-                        list_state
-                            .last_selected_index
-                            .map_or(event::Status::Ignored, |last| {
-                                if let Some(option) = self.options.get(last.0) {
-                                    shell.publish((self.on_selected)(last.0, option.clone()));
-                                    event::Status::Captured
-                                } else {
-                                    event::Status::Ignored
-                                }
-                            });
-                }
-                _ => {}
-            }
+        let mut status = if state.state.downcast_ref::<ListState>().drag.is_none() {
+            self.update_rows(
+                state, &event, bounds, cursor, renderer, clipboard, shell, viewport,
+            )
+        } else {
+            event::Status::Ignored
+        };
+        if status == event::Status::Ignored {
+            status = self.update(
+                state.state.downcast_mut::<ListState>(),
+                &event,
+                bounds,
+                cursor,
+                shell,
+                viewport,
+            );
         }
 
+        // The hovered and selected rows may have changed.
+        self.sync_rows(state, rows);
         status
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
+        let list_state = state.state.downcast_ref::<ListState>();
 
-        if bounds.contains(cursor.position().unwrap_or_default()) {
+        if list_state.drag.is_some_and(|drag| drag.gap.is_some()) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = cursor
+            .position()
+            .filter(|&position| bounds.contains(position))
+        else {
+            return mouse::Interaction::default();
+        };
+
+        let row = ((position.y - bounds.y) / self.option_height()) as usize;
+        let content = self
+            .contents
+            .borrow_mut()
+            .iter_mut()
+            .zip(&state.children)
+            .find(|(content, _)| content.row == row)
+            .map(|(content, tree)| {
+                let node = Self::row_layout(renderer, content, self.row_bounds(bounds, row));
+                content.element.as_widget().mouse_interaction(
+                    tree,
+                    Layout::new(&node),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default();
+
+        if content == mouse::Interaction::default() {
             mouse::Interaction::Pointer
         } else {
-            mouse::Interaction::default()
+            content
         }
     }
 
@@ -202,31 +1117,42 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: Cursor,
+        cursor: Cursor,
         viewport: &Rectangle,
     ) {
         use std::f32;
 
         let bounds = layout.bounds();
-        let option_height = self.text_size + (self.padding * 2.0);
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
-
-        let visible_options = &self.options[start..end.min(self.options.len())];
+        let option_height = self.option_height();
         let list_state = state.state.downcast_ref::<ListState>();
+        let appearance = theme.style(&self.style);
 
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = list_state
-                .last_selected_index
-                .map(|u| u.0 == i)
-                .unwrap_or_default();
-            let is_hovered = list_state.hovered_option == Some(i);
+        for row in self.visible_rows(bounds, viewport) {
+            if let Some(Entry::Header(title)) = self.rows.as_ref().and_then(|rows| rows.get(row)) {
+                let bounds = Rectangle {
+                    y: bounds.y + option_height * row as f32,
+                    height: option_height,
+                    ..bounds
+                };
+                self.draw_header(renderer, &appearance, title, bounds);
+                continue;
+            }
+
+            let Some((i, option)) = self
+                .option_index(row)
+                .and_then(|index| self.options.get(index).map(|option| (index, option)))
+            else {
+                continue;
+            };
+            let RowState {
+                is_hovered,
+                is_selected,
+                ..
+            } = self.row_state(list_state, row, i);
 
             let bounds = Rectangle {
                 x: bounds.x,
-                y: bounds.y + option_height * i as f32,
+                y: bounds.y + option_height * row as f32,
                 width: bounds.width,
                 height: self.text_size + (self.padding * 2.0),
             };
@@ -240,23 +1166,92 @@ where
                         border_color: Color::TRANSPARENT,
                     },
                     if is_selected {
-                        theme.style(&self.style).selected_background
+                        appearance.selected_background
                     } else {
-                        theme.style(&self.style).hovered_background
+                        appearance.hovered_background
                     },
                 );
             }
 
             let text_color = if is_selected {
-                theme.style(&self.style).selected_text_color
+                appearance.selected_text_color
             } else if is_hovered {
-                theme.style(&self.style).hovered_text_color
+                appearance.hovered_text_color
             } else {
-                theme.style(&self.style).text_color
+                appearance.text_color
+            };
+
+            if self.row_builder.is_some() {
+                let mut contents = self.contents.borrow_mut();
+                if let Some((content, tree)) = contents
+                    .iter_mut()
+                    .zip(&state.children)
+                    .find(|(content, _)| content.row == row)
+                {
+                    Self::draw_row(
+                        renderer, theme, tree, content, bounds, text_color, cursor, viewport,
+                    );
+                } else if let Some((_, mut content)) = self.row_content(list_state, row) {
+                    // Rows without kept content, e.g. just scrolled into view,
+                    // are built and drawn with a new state.
+                    let tree = Tree::new(&content.element);
+                    Self::draw_row(
+                        renderer,
+                        theme,
+                        &tree,
+                        &mut content,
+                        bounds,
+                        text_color,
+                        cursor,
+                        viewport,
+                    );
+                }
+                continue;
+            }
+
+            let label = option.to_string();
+            let matched = match self.rows.as_ref().and_then(|rows| rows.get(row)) {
+                Some(Entry::Option { matched, .. }) => matched.as_slice(),
+                _ => &[],
             };
 
+            for &position in matched {
+                let Some((byte, c)) = label.char_indices().nth(position) else {
+                    continue;
+                };
+                let measure = |text: &str| {
+                    renderer
+                        .measure(
+                            text,
+                            self.text_size,
+                            LineHeight::default(),
+                            self.font,
+                            Size::new(f32::INFINITY, f32::INFINITY),
+                            iced_widget::text::Shaping::Advanced,
+                        )
+                        .width
+                };
+                let x = measure(&label[..byte]);
+                let width = measure(&label[..byte + c.len_utf8()]) - x;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + x,
+                            y: bounds.center_y() - self.text_size / 2.0,
+                            width,
+                            height: self.text_size,
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.matched_background,
+                );
+            }
+
             renderer.fill_text(core::text::Text {
-                content: &option.to_string(),
+                content: &label,
                 bounds: Rectangle {
                     x: bounds.x,
                     y: bounds.center_y(),
@@ -272,6 +1267,31 @@ where
                 shaping: iced_widget::text::Shaping::Advanced,
             });
         }
+
+        if let Some(gap) = list_state.drag.and_then(|drag| drag.gap) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + option_height * gap as f32 - DROP_INDICATOR_HEIGHT / 2.0,
+                        height: DROP_INDICATOR_HEIGHT,
+                        ..bounds
+                    },
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.drop_indicator_color,
+            );
+        }
+
+        if let Some((title, y)) = self.sticky_header(bounds, viewport) {
+            let bounds = Rectangle {
+                y,
+                height: option_height,
+                ..bounds
+            };
+            self.draw_header(renderer, &appearance, title, bounds);
+        }
     }
 }
 
@@ -287,3 +1307,66 @@ where
         Element::new(list)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, cell::Cell, marker::PhantomData, rc::Rc};
+
+    use iced_widget::{
+        core::{
+            widget::{tree::State, Tree},
+            Font,
+        },
+        text,
+    };
+
+    use super::{List, ListState};
+
+    #[test]
+    fn row_builder_runs_once_per_row_state_test() {
+        let options = vec![
+            String::from("Rust"),
+            String::from("Elm"),
+            String::from("Go"),
+        ];
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+        let list: List<'_, String, (), crate::Renderer> = List {
+            options: Rc::new(Cow::Owned(options)),
+            font: Font::default(),
+            style: Default::default(),
+            on_selected: Rc::new(|_, _| ()),
+            on_selection_change: None,
+            padding: 5.0,
+            text_size: 12.0,
+            selected: None,
+            selection: None,
+            rows: None,
+            headers: Vec::new(),
+            row_builder: Some(Rc::new(move |option: &String, _| {
+                counter.set(counter.get() + 1);
+                text(option).into()
+            })),
+            contents: Default::default(),
+            on_reorder: None,
+            key: None,
+            phantomdata: PhantomData,
+        };
+        let mut tree = Tree::empty();
+        tree.state = State::new(ListState::default());
+
+        list.sync_rows(&mut tree, 0..3);
+        assert_eq!(calls.get(), 3);
+        assert_eq!(tree.children.len(), 3);
+
+        // Events and draws reuse the built rows.
+        list.sync_rows(&mut tree, 0..3);
+        assert_eq!(calls.get(), 3);
+
+        // Only the row whose state changed is built again.
+        tree.state.downcast_mut::<ListState>().hovered_option = Some(1);
+        list.sync_rows(&mut tree, 0..3);
+        assert_eq!(calls.get(), 4);
+        assert_eq!(list.contents.borrow().len(), 3);
+    }
+}
@@ -8,11 +8,18 @@ use iced_widget::{
     core::{
         self,
         alignment::{self, Horizontal, Vertical},
-        event, layout,
+        event, keyboard, layout,
         mouse::{self, Cursor},
-        renderer, touch,
-        widget::Tree,
-        Alignment, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+        overlay, renderer,
+        time::Instant,
+        touch,
+        widget::{
+            operation,
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        window, Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle,
+        Shell, Size, Vector, Widget,
     },
     runtime::Font,
     text::{self, LineHeight},
@@ -20,10 +27,12 @@ use iced_widget::{
 };
 
 pub mod tab_label;
+pub mod tab_options;
 pub use crate::style::tab_bar::{Appearance, StyleSheet};
 pub use tab_label::TabLabel;
+pub use tab_options::TabOptions;
 
-use crate::graphics::icons;
+use crate::{graphics::icons, native::overlay::TabTooltipOverlay};
 
 use std::marker::PhantomData;
 /// The default icon size.
@@ -36,6 +45,17 @@ const DEFAULT_CLOSE_SIZE: f32 = 16.0;
 const DEFAULT_PADDING: f32 = 5.0;
 /// The default spacing around the tabs.
 const DEFAULT_SPACING: f32 = 0.0;
+/// The distance scrolled per line of a mouse wheel.
+const SCROLL_LINE_HEIGHT: f32 = 60.0;
+/// The distance the cursor has to move with a pressed tab before it is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The width of the line showing where a dragged tab is dropped.
+const INDICATOR_WIDTH: f32 = 2.0;
+/// How fast the insertion indicator follows the cursor, as the fraction of the
+/// remaining distance per second.
+const INDICATOR_SPEED: f32 = 20.0;
+/// The width of the outline around the tab focused with the keyboard.
+const FOCUS_BORDER_WIDTH: f32 = 2.0;
 
 /// A tab bar to show tabs.
 ///
@@ -64,7 +84,7 @@ const DEFAULT_SPACING: f32 = 0.0;
 /// .set_active_tab(&TabId::One);
 /// ```
 #[allow(missing_debug_implementations)]
-pub struct TabBar<Message, TabId, Renderer = crate::Renderer>
+pub struct TabBar<'a, Message, TabId, Renderer = crate::Renderer>
 where
     Renderer: core::Renderer + core::text::Renderer,
     Renderer::Theme: StyleSheet,
@@ -73,13 +93,19 @@ where
     /// The index of the currently active tab.
     active_tab: usize,
     /// The vector containing the labels of the tabs.
-    tab_labels: Vec<TabLabel>,
+    tab_labels: Vec<TabLabel<'a, Message, Renderer>>,
     /// The vector containing the indices of the tabs.
     tab_indices: Vec<TabId>,
+    /// The vector containing the options of the tabs.
+    tab_options: Vec<TabOptions>,
     /// The function that produces the message when a tab is selected.
     on_select: Box<dyn Fn(TabId) -> Message>,
     /// The function that produces the message when the close icon was pressed.
     on_close: Option<Box<dyn Fn(TabId) -> Message>>,
+    /// The function that produces the message when a tab was dragged to a new index.
+    on_reorder: Option<Box<dyn Fn(TabId, usize) -> Message>>,
+    /// The function that produces the message when a tab was dragged out of the [`TabBar`].
+    on_tear_off: Option<Box<dyn Fn(TabId, Point) -> Message>>,
     /// The width of the [`TabBar`].
     width: Length,
     /// The width of the tabs of the [`TabBar`].
@@ -106,6 +132,12 @@ where
     style: <Renderer::Theme as StyleSheet>::Style,
     /// Where the icon is placed relative to text
     position: Position,
+    /// How tabs not fitting into the [`TabBar`] are handled.
+    overflow: Overflow,
+    /// The direction the tabs are laid out in.
+    orientation: Orientation,
+    /// Whether only the icons of [`TabLabel::IconText`] labels are shown.
+    icons_only: bool,
     #[allow(clippy::missing_docs_in_private_items)]
     _renderer: PhantomData<Renderer>,
 }
@@ -124,7 +156,85 @@ pub enum Position {
     Bottom,
 }
 
-impl<Message, TabId, Renderer> TabBar<Message, TabId, Renderer>
+/// How a [`TabBar`] handles tabs that do not fit into its width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// The tabs are squeezed into the width of the [`TabBar`], the default.
+    #[default]
+    Squeeze,
+    /// The tabs keep their width and are scrolled with the arrows at both
+    /// ends or the mouse wheel. The active tab is scrolled into view whenever
+    /// it changes.
+    Scroll,
+}
+
+/// The direction the tabs of a [`TabBar`] are laid out in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// The tabs are laid out from left to right, the default.
+    #[default]
+    Horizontal,
+    /// The tabs are stacked from top to bottom, e.g. in a side panel.
+    Vertical,
+}
+
+/// The state of a [`TabBar`].
+#[derive(Debug, Default)]
+struct TabBarState {
+    /// The horizontal offset of the scrolled tabs.
+    offset: f32,
+    /// The index of the active tab the offset was last updated for.
+    active_tab: usize,
+    /// The index of the tab moved to with the keyboard, which follows the
+    /// active tab.
+    focused_tab: usize,
+    /// Whether the focused tab has to be scrolled into view.
+    reveal: bool,
+    /// Whether the [`TabBar`] has the keyboard focus.
+    is_focused: bool,
+    /// Whether the focused tab is outlined, once the keyboard is used.
+    is_focus_visible: bool,
+    /// The pressed tab, which is dragged once the cursor moves.
+    drag: Option<TabDrag>,
+    /// The hovered tab showing its tooltip, with the position of the cursor.
+    tooltip: Option<(usize, Point)>,
+}
+
+impl operation::Focusable for TabBarState {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.is_focus_visible = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_focus_visible = false;
+    }
+}
+
+/// A tab of a [`TabBar`] being dragged to another position.
+#[derive(Debug, Clone, Copy)]
+struct TabDrag {
+    /// The index of the dragged tab.
+    index: usize,
+    /// The position the tab was pressed at.
+    origin: Point,
+    /// The gap between the tabs the tab is dropped into, once the cursor
+    /// moved far enough to start dragging.
+    gap: Option<usize>,
+    /// Whether the tab is dragged away from the [`TabBar`] to tear it off.
+    is_torn_off: bool,
+    /// The animated position of the insertion indicator along the [`TabBar`].
+    indicator: f32,
+    /// The point in time the indicator was last moved.
+    last_frame: Option<Instant>,
+}
+
+impl<'a, Message, TabId, Renderer> TabBar<'a, Message, TabId, Renderer>
 where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet,
@@ -151,16 +261,22 @@ where
     ///     * a vector containing the [`TabLabel`]s of the [`TabBar`].
     ///     * the function that will be called if a tab is selected by the user.
     ///         It takes the index of the selected tab.
-    pub fn with_tab_labels<F>(tab_labels: Vec<(TabId, TabLabel)>, on_select: F) -> Self
+    pub fn with_tab_labels<F>(
+        tab_labels: Vec<(TabId, TabLabel<'a, Message, Renderer>)>,
+        on_select: F,
+    ) -> Self
     where
         F: 'static + Fn(TabId) -> Message,
     {
         Self {
             active_tab: 0,
             tab_indices: tab_labels.iter().map(|(id, _)| id.clone()).collect(),
+            tab_options: vec![TabOptions::default(); tab_labels.len()],
             tab_labels: tab_labels.into_iter().map(|(_, label)| label).collect(),
             on_select: Box::new(on_select),
             on_close: None,
+            on_reorder: None,
+            on_tear_off: None,
             width: Length::Fill,
             tab_width: Length::Fill,
             height: Length::Shrink,
@@ -174,6 +290,9 @@ where
             text_font: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
             position: Position::default(),
+            overflow: Overflow::default(),
+            orientation: Orientation::default(),
+            icons_only: false,
             _renderer: PhantomData,
         }
     }
@@ -209,6 +328,35 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab of the [`TabBar`]
+    /// is dragged to another position, with the id of the tab and the index
+    /// it has to be moved to.
+    ///
+    /// The tabs are owned by the application, which has to move them.
+    #[must_use]
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the message that will be produced when a tab is dragged away from
+    /// the [`TabBar`] and dropped, with the id of the tab and the position of
+    /// the cursor, e.g. to move the tab into another pane or window.
+    ///
+    /// A tab is torn off once it is dragged further away from the [`TabBar`]
+    /// than its height, or its width if it is vertical.
+    #[must_use]
+    pub fn on_tear_off<F>(mut self, on_tear_off: F) -> Self
+    where
+        F: 'static + Fn(TabId, Point) -> Message,
+    {
+        self.on_tear_off = Some(Box::new(on_tear_off));
+        self
+    }
+
     /// Sets the width of the [`TabBar`].
     #[must_use]
     pub fn width(mut self, width: Length) -> Self {
@@ -310,12 +458,68 @@ where
 
     /// Pushes a [`TabLabel`](crate::tab_bar::TabLabel) to the [`TabBar`].
     #[must_use]
-    pub fn push(mut self, id: TabId, tab_label: TabLabel) -> Self {
-        self.tab_labels.push(tab_label);
-        self.tab_indices.push(id);
+    pub fn push(self, id: TabId, tab_label: TabLabel<'a, Message, Renderer>) -> Self {
+        self.push_with_options(id, tab_label, TabOptions::default())
+    }
+
+    /// Pushes a [`TabLabel`](crate::tab_bar::TabLabel) with its
+    /// [`TabOptions`] to the [`TabBar`].
+    ///
+    /// A pinned tab is inserted after the pinned tabs pushed before it.
+    #[must_use]
+    pub fn push_with_options(
+        mut self,
+        id: TabId,
+        tab_label: TabLabel<'a, Message, Renderer>,
+        options: TabOptions,
+    ) -> Self {
+        let _ = self.insert(id, tab_label, options);
         self
     }
 
+    /// Inserts a tab at the end, or after the pinned tabs if it is pinned,
+    /// and returns its index.
+    pub(crate) fn insert(
+        &mut self,
+        id: TabId,
+        tab_label: TabLabel<'a, Message, Renderer>,
+        options: TabOptions,
+    ) -> usize {
+        let index = if options.is_pinned {
+            self.pinned_count()
+        } else {
+            self.tab_indices.len()
+        };
+
+        self.tab_labels.insert(index, tab_label);
+        self.tab_indices.insert(index, id);
+        self.tab_options.insert(index, options);
+        index
+    }
+
+    /// Returns the number of pinned tabs at the start of the [`TabBar`].
+    fn pinned_count(&self) -> usize {
+        self.tab_options
+            .iter()
+            .take_while(|options| options.is_pinned)
+            .count()
+    }
+
+    /// Returns whether the tab can be selected.
+    fn is_enabled(&self, index: usize) -> bool {
+        self.tab_options
+            .get(index)
+            .is_some_and(|options| !options.is_disabled)
+    }
+
+    /// Returns whether the tab has a close icon.
+    fn is_closable(&self, index: usize) -> bool {
+        self.on_close.is_some()
+            && self.tab_options.get(index).is_some_and(|options| {
+                options.is_closable && !options.is_pinned && !options.is_disabled
+            })
+    }
+
     /// Sets up the active tab on the [`TabBar`].
     #[must_use]
     pub fn set_active_tab(mut self, active_tab: &TabId) -> Self {
@@ -333,14 +537,448 @@ where
         self.position = position;
         self
     }
+
+    /// Sets how tabs that do not fit into the [`TabBar`] are handled.
+    /// Defaults to [`Overflow::Squeeze`].
+    #[must_use]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the [`Orientation`] the tabs of the [`TabBar`] are laid out in.
+    /// Defaults to [`Orientation::Horizontal`].
+    ///
+    /// The tabs of a vertical [`TabBar`] are as wide as its widest tab if its
+    /// width is [`Length::Shrink`].
+    #[must_use]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets whether only the icons of [`TabLabel::IconText`] labels are shown,
+    /// e.g. to keep a vertical [`TabBar`] narrow.
+    #[must_use]
+    pub fn icons_only(mut self, icons_only: bool) -> Self {
+        self.icons_only = icons_only;
+        self
+    }
+
+    /// Returns whether the tabs are stacked vertically.
+    fn is_vertical(&self) -> bool {
+        self.orientation == Orientation::Vertical
+    }
+
+    /// Returns the coordinate of the point along the direction the tabs are
+    /// laid out in.
+    fn main(&self, point: Point) -> f32 {
+        if self.is_vertical() {
+            point.y
+        } else {
+            point.x
+        }
+    }
+
+    /// Returns the length of the size along the direction the tabs are laid
+    /// out in.
+    fn main_length(&self, size: Size) -> f32 {
+        if self.is_vertical() {
+            size.height
+        } else {
+            size.width
+        }
+    }
+
+    /// Returns the start and the length of the rectangle along the direction
+    /// the tabs are laid out in.
+    fn main_axis(&self, rectangle: Rectangle) -> (f32, f32) {
+        (
+            self.main(rectangle.position()),
+            self.main_length(rectangle.size()),
+        )
+    }
+
+    /// Returns the vector of the given length along the direction the tabs
+    /// are laid out in.
+    fn main_vector(&self, length: f32) -> Vector {
+        if self.is_vertical() {
+            Vector::new(0.0, length)
+        } else {
+            Vector::new(length, 0.0)
+        }
+    }
+
+    /// Returns the part of the bounds between the start and the length along
+    /// the direction the tabs are laid out in.
+    fn main_slice(&self, bounds: Rectangle, start: f32, length: f32) -> Rectangle {
+        if self.is_vertical() {
+            Rectangle {
+                y: start,
+                height: length,
+                ..bounds
+            }
+        } else {
+            Rectangle {
+                x: start,
+                width: length,
+                ..bounds
+            }
+        }
+    }
+
+    /// Returns the width of the arrows scrolling the tabs.
+    fn arrow_width(&self) -> f32 {
+        self.close_size + self.padding * 2.0
+    }
+
+    /// Returns the area the tabs are scrolled in between the arrows, or
+    /// [`None`] if the tabs fit into the [`TabBar`].
+    fn scroll_viewport(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        let bounds = layout.bounds();
+        let (start, length) = self.main_axis(bounds);
+        let (last, last_length) = self.main_axis(layout.children().last()?.bounds());
+        let arrow_width = self.arrow_width();
+
+        (self.overflow == Overflow::Scroll && last + last_length > start + length).then(|| {
+            self.main_slice(
+                bounds,
+                start + arrow_width,
+                (length - arrow_width * 2.0).max(0.0),
+            )
+        })
+    }
+
+    /// Returns the offset the tabs can be scrolled by at most.
+    fn max_offset(&self, layout: Layout<'_>, viewport: Rectangle) -> f32 {
+        let (start, length) = self.main_axis(viewport);
+
+        layout.children().last().map_or(0.0, |tab| {
+            let (tab, tab_length) = self.main_axis(tab.bounds());
+            (tab + tab_length - start - length).max(0.0)
+        })
+    }
+
+    /// Returns the offset of the scrolled tabs, scrolling the focused tab into
+    /// view if it changed.
+    fn scroll_offset(&self, state: &TabBarState, layout: Layout<'_>, viewport: Rectangle) -> f32 {
+        let mut offset = state.offset;
+
+        if state.reveal {
+            if let Some(tab) = layout.children().nth(state.focused_tab) {
+                let (tab, tab_length) = self.main_axis(tab.bounds());
+                let (start, length) = self.main_axis(viewport);
+                offset = offset
+                    .min(tab - start)
+                    .max(tab + tab_length - start - length);
+            }
+        }
+
+        offset.clamp(0.0, self.max_offset(layout, viewport))
+    }
+
+    /// Scrolls the tabs with the mouse wheel or by pressing the arrows.
+    fn scroll(
+        &self,
+        state: &mut TabBarState,
+        event: &Event,
+        cursor: Cursor,
+        layout: Layout<'_>,
+        viewport: Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let max_offset = self.max_offset(layout, viewport);
+        let (start, length) = self.main_axis(viewport);
+
+        let delta = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let (x, y) = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x * SCROLL_LINE_HEIGHT, y * SCROLL_LINE_HEIGHT)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+                // Vertical wheels scroll horizontal tabs as well.
+                -if !self.is_vertical() && x.abs() > f32::EPSILON {
+                    x
+                } else {
+                    y
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match cursor.position() {
+                Some(position) if bounds.contains(position) && self.main(position) < start => {
+                    -length / 2.0
+                }
+                Some(position)
+                    if bounds.contains(position) && self.main(position) >= start + length =>
+                {
+                    length / 2.0
+                }
+                _ => return event::Status::Ignored,
+            },
+            _ => return event::Status::Ignored,
+        };
+
+        state.offset = (state.offset + delta).clamp(0.0, max_offset);
+        event::Status::Captured
+    }
+
+    /// Moves the focus between the tabs and activates or closes the focused
+    /// tab with the keyboard.
+    fn navigate(
+        &self,
+        state: &mut TabBarState,
+        key_code: keyboard::KeyCode,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(last) = self.tab_indices.len().checked_sub(1) else {
+            return event::Status::Ignored;
+        };
+        let focused = state.focused_tab.min(last);
+        let (previous, next) = if self.is_vertical() {
+            (keyboard::KeyCode::Up, keyboard::KeyCode::Down)
+        } else {
+            (keyboard::KeyCode::Left, keyboard::KeyCode::Right)
+        };
+
+        // Disabled tabs are skipped.
+        let moved_to = match key_code {
+            key_code if key_code == previous => {
+                (0..focused).rev().find(|&index| self.is_enabled(index))
+            }
+            key_code if key_code == next => {
+                (focused + 1..=last).find(|&index| self.is_enabled(index))
+            }
+            keyboard::KeyCode::Home => (0..=last).find(|&index| self.is_enabled(index)),
+            keyboard::KeyCode::End => (0..=last).rev().find(|&index| self.is_enabled(index)),
+            keyboard::KeyCode::Enter
+            | keyboard::KeyCode::NumpadEnter
+            | keyboard::KeyCode::Space
+                if self.is_enabled(focused) =>
+            {
+                shell.publish((self.on_select)(self.tab_indices[focused].clone()));
+                None
+            }
+            keyboard::KeyCode::Delete if self.is_closable(focused) => {
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close(self.tab_indices[focused].clone()));
+                }
+                None
+            }
+            _ => return event::Status::Ignored,
+        };
+        if let Some(index) = moved_to {
+            state.focused_tab = index;
+        }
+
+        state.reveal = true;
+        state.is_focus_visible = true;
+        event::Status::Captured
+    }
+
+    /// Returns the message selecting the tab after the active one, or the one
+    /// before it if `backwards` is set, wrapping around at the ends.
+    #[cfg(feature = "tabs")]
+    pub(crate) fn cycle(&self, backwards: bool) -> Option<Message> {
+        let count = self.tab_indices.len();
+        let step = if backwards { count.checked_sub(1)? } else { 1 };
+        // Disabled tabs are skipped.
+        let index = (1..count)
+            .map(|steps| (self.active_tab + step * steps) % count)
+            .find(|&index| self.is_enabled(index))?;
+
+        Some((self.on_select)(self.tab_indices[index].clone()))
+    }
+
+    /// Returns the gap between the tabs nearest to the position along the
+    /// [`TabBar`].
+    fn gap_at(&self, layout: Layout<'_>, position: f32) -> usize {
+        layout
+            .children()
+            .take_while(|tab| {
+                let (start, length) = self.main_axis(tab.bounds());
+                start + length / 2.0 < position
+            })
+            .count()
+    }
+
+    /// Returns the position of the gap between the tabs along the [`TabBar`].
+    fn gap_position(&self, layout: Layout<'_>, gap: usize) -> f32 {
+        let (start, _) = self.main_axis(layout.bounds());
+
+        gap.checked_sub(1)
+            .and_then(|before| layout.children().nth(before))
+            .map_or_else(
+                || {
+                    layout.children().next().map_or(start, |tab| {
+                        self.main(tab.bounds().position()) - self.spacing / 2.0
+                    })
+                },
+                |tab| {
+                    let (tab, tab_length) = self.main_axis(tab.bounds());
+                    tab + tab_length + self.spacing / 2.0
+                },
+            )
+    }
+
+    /// Moves the insertion indicator of the dragged tab with the cursor and
+    /// reorders or tears off the tab once it is dropped.
+    fn drag(
+        &self,
+        state: &mut TabBarState,
+        event: &Event,
+        cursor: Cursor,
+        layout: Layout<'_>,
+        offset: f32,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(drag) = state.drag.as_mut() else {
+            return event::Status::Ignored;
+        };
+        let bounds = layout.bounds();
+
+        match *event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if drag.gap.is_none() && drag.origin.distance(position) < DRAG_THRESHOLD {
+                    return event::Status::Ignored;
+                }
+
+                let (distance, thickness) = if self.is_vertical() {
+                    ((position.x - bounds.center_x()).abs(), bounds.width)
+                } else {
+                    ((position.y - bounds.center_y()).abs(), bounds.height)
+                };
+                drag.is_torn_off = self.on_tear_off.is_some() && distance > thickness * 1.5;
+
+                // Pinned tabs stay in front of the others.
+                let pinned_count = self.pinned_count();
+                let gap = self.gap_at(layout, self.main(position) + offset);
+                let gap = if drag.index < pinned_count {
+                    gap.min(pinned_count)
+                } else {
+                    gap.max(pinned_count)
+                };
+                if drag.gap.is_none() {
+                    drag.indicator = self.gap_position(layout, gap);
+                }
+                drag.gap = Some(gap);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                event::Status::Captured
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let Some(gap) = drag.gap else {
+                    return event::Status::Ignored;
+                };
+                let target = self.gap_position(layout, gap);
+                let elapsed = drag
+                    .last_frame
+                    .map_or(0.0, |last_frame| (now - last_frame).as_secs_f32());
+
+                drag.indicator += (target - drag.indicator) * (elapsed * INDICATOR_SPEED).min(1.0);
+                if (target - drag.indicator).abs() < 0.5 {
+                    drag.indicator = target;
+                    drag.last_frame = None;
+                } else {
+                    drag.last_frame = Some(now);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let TabDrag {
+                    index,
+                    gap,
+                    is_torn_off,
+                    ..
+                } = *drag;
+                state.drag = None;
+
+                let (Some(gap), Some(id)) = (gap, self.tab_indices.get(index)) else {
+                    return event::Status::Ignored;
+                };
+
+                if is_torn_off {
+                    if let (Some(on_tear_off), Some(position)) =
+                        (&self.on_tear_off, cursor.position())
+                    {
+                        shell.publish(on_tear_off(id.clone(), position));
+                    }
+                } else if let Some(on_reorder) = &self.on_reorder {
+                    let to = if gap > index { gap - 1 } else { gap };
+                    if to != index {
+                        shell.publish(on_reorder(id.clone(), to));
+                    }
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+/// Moves the cursor over the scrolled tabs by the scroll offset, so that it
+/// can be compared with the bounds of the tabs.
+fn scrolled_cursor(cursor: Cursor, viewport: Option<Rectangle>, offset: Vector) -> Cursor {
+    let Some(viewport) = viewport else {
+        return cursor;
+    };
+
+    match cursor.position() {
+        Some(position) if viewport.contains(position) => Cursor::Available(position + offset),
+        _ => Cursor::Unavailable,
+    }
+}
+
+/// Returns whether the [`TabBar`] with the given state has the keyboard focus.
+#[cfg(feature = "tabs")]
+pub(crate) fn is_focused(tree: &Tree) -> bool {
+    tree.state.downcast_ref::<TabBarState>().is_focused
 }
 
-impl<Message, TabId, Renderer> Widget<Message, Renderer> for TabBar<Message, TabId, Renderer>
+impl<Message, TabId, Renderer> Widget<Message, Renderer> for TabBar<'_, Message, TabId, Renderer>
 where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet + text::StyleSheet,
     TabId: Eq + Clone,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<TabBarState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(TabBarState {
+            active_tab: self.active_tab,
+            focused_tab: self.active_tab,
+            reveal: true,
+            ..TabBarState::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.tab_labels.iter().map(label_tree).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<TabBarState>();
+        if state.active_tab != self.active_tab {
+            state.active_tab = self.active_tab;
+            state.focused_tab = self.active_tab;
+            state.reveal = true;
+        }
+
+        tree.diff_children_custom(
+            &self.tab_labels,
+            |state, tab_label| match tab_label {
+                TabLabel::Element(element) => state.diff(element),
+                _ => *state = Tree::empty(),
+            },
+            label_tree,
+        );
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -376,170 +1014,419 @@ where
                 .vertical_alignment(alignment::Vertical::Center)
         }
 
-        self.tab_labels
-            .iter()
-            .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
-                let mut label_row = Row::new()
-                    .push(
-                        match tab_label {
-                            TabLabel::Icon(icon) => Column::new()
-                                .align_items(Alignment::Center)
-                                .push(layout_icon(icon, self.icon_size, self.icon_font)),
-
-                            TabLabel::Text(text) => Column::new()
-                                .align_items(Alignment::Center)
-                                .push(layout_text(text, self.icon_size, self.icon_font)),
-
-                            TabLabel::IconText(icon, text) => {
-                                let mut column = Column::new().align_items(Alignment::Center);
-
-                                match self.position {
-                                    Position::Top => {
-                                        column = column
+        // The label of a tab fills the height of a horizontal tab bar, the
+        // tabs of a vertical one keep their height.
+        let label_height = if self.is_vertical() {
+            Length::Shrink
+        } else {
+            self.height
+        };
+
+        let layout_tabs =
+            |tab_width: Length, width: Length, height: Length, limits: &layout::Limits| {
+                let tabs = self
+                    .tab_labels
+                    .iter()
+                    .zip(&self.tab_options)
+                    .enumerate()
+                    .map(|(index, (tab_label, options))| {
+                        // Pinned tabs are as narrow as their label.
+                        let tab_width = if options.is_pinned && !self.is_vertical() {
+                            Length::Shrink
+                        } else {
+                            tab_width
+                        };
+
+                        let mut label_row = Row::new()
+                            .push(
+                                match tab_label {
+                                    TabLabel::Icon(icon) => Column::new()
+                                        .align_items(Alignment::Center)
+                                        .push(layout_icon(icon, self.icon_size, self.icon_font)),
+
+                                    TabLabel::Text(text) => Column::new()
+                                        .align_items(Alignment::Center)
+                                        .push(layout_text(text, self.icon_size, self.icon_font)),
+
+                                    TabLabel::IconText(icon, _)
+                                        if self.icons_only || options.is_pinned =>
+                                    {
+                                        Column::new()
+                                            .align_items(Alignment::Center)
                                             .push(layout_icon(icon, self.icon_size, self.icon_font))
-                                            .push(layout_text(
-                                                text,
-                                                self.icon_size,
-                                                self.icon_font,
-                                            ));
-                                    }
-                                    Position::Right => {
-                                        column = column.push(
-                                            Row::new()
-                                                .align_items(Alignment::Center)
-                                                .push(layout_icon(
-                                                    icon,
-                                                    self.icon_size,
-                                                    self.icon_font,
-                                                ))
-                                                .push(layout_text(
-                                                    text,
-                                                    self.icon_size,
-                                                    self.icon_font,
-                                                )),
-                                        );
-                                    }
-                                    Position::Left => {
-                                        column = column.push(
-                                            Row::new()
-                                                .align_items(Alignment::Center)
-                                                .push(layout_text(
-                                                    text,
-                                                    self.icon_size,
-                                                    self.icon_font,
-                                                ))
-                                                .push(layout_icon(
-                                                    icon,
-                                                    self.icon_size,
-                                                    self.icon_font,
-                                                )),
-                                        );
                                     }
-                                    Position::Bottom => {
-                                        column = column
-                                            .push(layout_text(text, self.icon_size, self.icon_font))
-                                            .push(layout_icon(
-                                                icon,
-                                                self.icon_size,
-                                                self.icon_font,
-                                            ));
+
+                                    TabLabel::IconText(icon, text) => {
+                                        let mut column =
+                                            Column::new().align_items(Alignment::Center);
+
+                                        match self.position {
+                                            Position::Top => {
+                                                column = column
+                                                    .push(layout_icon(
+                                                        icon,
+                                                        self.icon_size,
+                                                        self.icon_font,
+                                                    ))
+                                                    .push(layout_text(
+                                                        text,
+                                                        self.icon_size,
+                                                        self.icon_font,
+                                                    ));
+                                            }
+                                            Position::Right => {
+                                                column = column.push(
+                                                    Row::new()
+                                                        .align_items(Alignment::Center)
+                                                        .push(layout_icon(
+                                                            icon,
+                                                            self.icon_size,
+                                                            self.icon_font,
+                                                        ))
+                                                        .push(layout_text(
+                                                            text,
+                                                            self.icon_size,
+                                                            self.icon_font,
+                                                        )),
+                                                );
+                                            }
+                                            Position::Left => {
+                                                column = column.push(
+                                                    Row::new()
+                                                        .align_items(Alignment::Center)
+                                                        .push(layout_text(
+                                                            text,
+                                                            self.icon_size,
+                                                            self.icon_font,
+                                                        ))
+                                                        .push(layout_icon(
+                                                            icon,
+                                                            self.icon_size,
+                                                            self.icon_font,
+                                                        )),
+                                                );
+                                            }
+                                            Position::Bottom => {
+                                                column = column
+                                                    .push(layout_text(
+                                                        text,
+                                                        self.icon_size,
+                                                        self.icon_font,
+                                                    ))
+                                                    .push(layout_icon(
+                                                        icon,
+                                                        self.icon_size,
+                                                        self.icon_font,
+                                                    ));
+                                            }
+                                        }
+
+                                        column
                                     }
+
+                                    TabLabel::Element(element) => Column::new()
+                                        .align_items(Alignment::Center)
+                                        .push(Element::new(LabelElement(element))),
                                 }
+                                .width(tab_width)
+                                .height(label_height),
+                            )
+                            .align_items(Alignment::Center)
+                            .padding(self.padding)
+                            .width(tab_width);
 
-                                column
-                            }
+                        if self.is_closable(index) {
+                            label_row = label_row.push(
+                                Row::new()
+                                    .width(Length::Fixed(self.close_size * 1.3 + 1.0))
+                                    .height(Length::Fixed(self.close_size * 1.3 + 1.0))
+                                    .align_items(Alignment::Center),
+                            );
                         }
-                        .width(self.tab_width)
-                        .height(self.height),
-                    )
-                    .align_items(Alignment::Center)
-                    .padding(self.padding)
-                    .width(self.tab_width);
-
-                if self.on_close.is_some() {
-                    label_row = label_row.push(
-                        Row::new()
-                            .width(Length::Fixed(self.close_size * 1.3 + 1.0))
-                            .height(Length::Fixed(self.close_size * 1.3 + 1.0))
-                            .align_items(Alignment::Center),
-                    );
+
+                        label_row.into()
+                    })
+                    .collect();
+
+                if self.is_vertical() {
+                    Column::with_children(tabs)
+                        .width(width)
+                        .height(height)
+                        .spacing(self.spacing)
+                        .layout(renderer, limits)
+                } else {
+                    Row::with_children(tabs)
+                        .width(width)
+                        .height(height)
+                        .spacing(self.spacing)
+                        .layout(renderer, limits)
                 }
+            };
 
-                row.push(label_row)
-            })
-            .width(self.width)
-            .height(self.height)
-            .spacing(self.spacing)
-            .layout(renderer, &limits.loose())
+        let node = if self.is_vertical() && self.width == Length::Shrink {
+            // The tabs of a shrinking vertical tab bar are as wide as the widest one.
+            let natural = layout_tabs(Length::Shrink, Length::Shrink, self.height, &limits.loose());
+            layout_tabs(
+                self.tab_width,
+                Length::Fixed(natural.size().width),
+                self.height,
+                &limits.loose(),
+            )
+        } else {
+            layout_tabs(self.tab_width, self.width, self.height, &limits.loose())
+        };
+        if self.overflow == Overflow::Squeeze {
+            return node;
+        }
+
+        // Tabs not fitting at their natural size are scrolled between the
+        // arrows instead of being squeezed.
+        let natural = if self.is_vertical() {
+            layout_tabs(
+                self.tab_width,
+                Length::Fixed(node.size().width),
+                Length::Shrink,
+                &layout::Limits::new(Size::ZERO, Size::new(limits.max().width, f32::INFINITY)),
+            )
+        } else {
+            layout_tabs(
+                Length::Shrink,
+                Length::Shrink,
+                self.height,
+                &layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, limits.max().height)),
+            )
+        };
+        if self.main_length(natural.size()) <= self.main_length(node.size()) {
+            return node;
+        }
+
+        let arrow_width = self.arrow_width();
+        layout::Node::with_children(
+            if self.is_vertical() {
+                node.size()
+            } else {
+                Size::new(node.size().width, natural.size().height)
+            },
+            natural
+                .children()
+                .iter()
+                .map(|tab| tab.clone().translate(self.main_vector(arrow_width)))
+                .collect(),
+        )
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
+        let scroll_viewport = self.scroll_viewport(layout);
+        let tab_bar_state = state.state.downcast_mut::<TabBarState>();
+        let offset = scroll_viewport.map_or(0.0, |viewport| {
+            self.scroll_offset(tab_bar_state, layout, viewport)
+        });
+        tab_bar_state.offset = offset;
+        tab_bar_state.reveal = false;
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            tab_bar_state.is_focused = cursor.is_over(layout.bounds());
+            tab_bar_state.is_focus_visible = false;
+            tab_bar_state.tooltip = None;
+        }
+
+        if let Some(viewport) = scroll_viewport {
+            if self.scroll(tab_bar_state, &event, cursor, layout, viewport)
+                == event::Status::Captured
+            {
+                return event::Status::Captured;
+            }
+        }
+        if self.drag(tab_bar_state, &event, cursor, layout, offset, shell)
+            == event::Status::Captured
+        {
+            return event::Status::Captured;
+        }
+        let cursor = scrolled_cursor(cursor, scroll_viewport, self.main_vector(offset));
+
+        let mut status = event::Status::Ignored;
+        for ((tab_label, tree), layout) in self
+            .tab_labels
+            .iter_mut()
+            .zip(&mut state.children)
+            .zip(layout.children())
+        {
+            if let TabLabel::Element(element) = tab_label {
+                status = status.merge(element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    element_layout(layout),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ));
+            }
+        }
+        if status == event::Status::Captured {
+            return status;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout
-                    .bounds()
-                    .contains(cursor.position().unwrap_or_default())
-                {
+                // The cursor over scrolled tabs may lie beyond the bounds of the
+                // tab bar, but is only available over the visible tabs.
+                if let Some(position) = cursor.position() {
                     let tabs_map: Vec<bool> = layout
                         .children()
-                        .map(|layout| {
-                            layout
-                                .bounds()
-                                .contains(cursor.position().unwrap_or_default())
-                        })
+                        .map(|layout| layout.bounds().contains(position))
                         .collect();
 
                     if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
+                        if !self.is_enabled(new_selected) {
+                            return event::Status::Captured;
+                        }
+
+                        let is_close_pressed = self.is_closable(new_selected) && {
+                            let tab_layout = layout.children().nth(new_selected).expect(
+                                "Native: Layout should have a tab layout at the selected index",
+                            );
+                            let cross_layout = tab_layout
+                                .children()
+                                .nth(1)
+                                .expect("Native: Layout should have a close layout");
+
+                            cross_layout.bounds().contains(position)
+                        };
+
                         shell.publish(
                             self.on_close
                                 .as_ref()
-                                .filter(|_on_close| {
-                                    let tab_layout = layout.children().nth(new_selected).expect("Native: Layout should have a tab layout at the selected index");
-                                    let cross_layout = tab_layout.children().nth(1).expect("Native: Layout should have a close layout");
-
-                                    cross_layout.bounds().contains(cursor.position().unwrap_or_default())
-                                })
+                                .filter(|_on_close| is_close_pressed)
                                 .map_or_else(
                                     || (self.on_select)(self.tab_indices[new_selected].clone()),
                                     |on_close| (on_close)(self.tab_indices[new_selected].clone()),
                                 ),
                         );
+
+                        tab_bar_state.focused_tab = new_selected;
+                        if !is_close_pressed
+                            && (self.on_reorder.is_some() || self.on_tear_off.is_some())
+                        {
+                            tab_bar_state.drag = Some(TabDrag {
+                                index: new_selected,
+                                origin: position - self.main_vector(offset),
+                                gap: None,
+                                is_torn_off: false,
+                                indicator: 0.0,
+                                last_frame: None,
+                            });
+                        }
                         return event::Status::Captured;
                     }
                 }
                 event::Status::Ignored
             }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                tab_bar_state.tooltip = cursor
+                    .position()
+                    .and_then(|cursor| {
+                        layout
+                            .children()
+                            .position(|tab| tab.bounds().contains(cursor))
+                    })
+                    .filter(|&index| self.tab_options[index].tooltip.is_some())
+                    .map(|index| (index, position));
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                tab_bar_state.tooltip = None;
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if tab_bar_state.is_focused =>
+            {
+                self.navigate(tab_bar_state, key_code, shell)
+            }
             _ => event::Status::Ignored,
         }
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<TabBarState>(), None);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let (index, cursor_position) = state.state.downcast_ref::<TabBarState>().tooltip?;
+        let tooltip = self.tab_options.get(index)?.tooltip.as_deref()?;
+
+        Some(
+            TabTooltipOverlay::new(
+                tooltip,
+                cursor_position,
+                self.text_font.unwrap_or_default(),
+                self.text_size,
+                &self.style,
+            )
+            .overlay(),
+        )
+    }
+
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
+        let tab_bar_state = state.state.downcast_ref::<TabBarState>();
+        if tab_bar_state.drag.is_some_and(|drag| drag.gap.is_some()) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let scroll_viewport = self.scroll_viewport(layout);
+        if let Some(viewport) = scroll_viewport {
+            let is_over_arrow = cursor.position().is_some_and(|position| {
+                layout.bounds().contains(position) && !viewport.contains(position)
+            });
+            if is_over_arrow {
+                return mouse::Interaction::Pointer;
+            }
+        }
+        let offset = scroll_viewport.map_or(0.0, |viewport| {
+            self.scroll_offset(tab_bar_state, layout, viewport)
+        });
+        let cursor = scrolled_cursor(cursor, scroll_viewport, self.main_vector(offset));
+
         let children = layout.children();
         let mut mouse_interaction = mouse::Interaction::default();
 
-        for layout in children {
+        for (index, layout) in children.enumerate() {
             let is_mouse_over = layout
                 .bounds()
                 .contains(cursor.position().unwrap_or_default());
-            let new_mouse_interaction = if is_mouse_over {
+            let new_mouse_interaction = if is_mouse_over && self.is_enabled(index) {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
@@ -555,13 +1442,13 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        state: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
         cursor: Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
         let children = layout.children();
@@ -584,20 +1471,123 @@ where
                 .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
 
-        for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
-            draw_tab(
-                renderer,
-                tab,
-                layout,
-                self.position,
-                theme,
-                &self.style,
-                i == self.get_active_tab_idx(),
-                cursor,
-                (self.icon_font.unwrap_or(icons::ICON_FONT), self.icon_size),
-                (self.text_font.unwrap_or_default(), self.text_size),
-                self.close_size,
-            );
+        let scroll_viewport = self.scroll_viewport(layout);
+        let offset = scroll_viewport.map_or(0.0, |scroll_viewport| {
+            self.scroll_offset(state.state.downcast_ref(), layout, scroll_viewport)
+        });
+        let tab_cursor = scrolled_cursor(cursor, scroll_viewport, self.main_vector(offset));
+
+        let tab_bar_state = state.state.downcast_ref::<TabBarState>();
+        let draw_tabs = |renderer: &mut Renderer| {
+            for ((((i, tab), options), tree), layout) in self
+                .tab_labels
+                .iter()
+                .enumerate()
+                .zip(&self.tab_options)
+                .zip(&state.children)
+                .zip(children)
+            {
+                draw_tab(
+                    renderer,
+                    tab,
+                    tree,
+                    layout,
+                    viewport,
+                    self.position,
+                    self.icons_only || options.is_pinned,
+                    theme,
+                    &self.style,
+                    i == self.get_active_tab_idx(),
+                    options.is_disabled,
+                    tab_cursor,
+                    (self.icon_font.unwrap_or(icons::ICON_FONT), self.icon_size),
+                    (self.text_font.unwrap_or_default(), self.text_size),
+                    self.close_size,
+                );
+
+                if i == tab_bar_state.focused_tab && tab_bar_state.is_focus_visible {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: layout.bounds(),
+                            border_radius: (0.0).into(),
+                            border_width: FOCUS_BORDER_WIDTH,
+                            border_color: style_sheet.text_color,
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+            }
+
+            if let Some(drag) = tab_bar_state
+                .drag
+                .filter(|drag| drag.gap.is_some() && !drag.is_torn_off)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.main_slice(
+                            bounds,
+                            drag.indicator - INDICATOR_WIDTH / 2.0,
+                            INDICATOR_WIDTH,
+                        ),
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    style_sheet.icon_color,
+                );
+            }
+        };
+
+        let Some(scroll_viewport) = scroll_viewport else {
+            draw_tabs(renderer);
+            return;
+        };
+
+        renderer.with_layer(scroll_viewport, |renderer| {
+            renderer.with_translation(self.main_vector(-offset), draw_tabs);
+        });
+
+        // The arrows are faded once the tabs cannot be scrolled further.
+        let (start, _) = self.main_axis(bounds);
+        let (viewport_start, viewport_length) = self.main_axis(scroll_viewport);
+        let (backward, forward) = if self.is_vertical() {
+            (icons::Icon::CaretUpFill, icons::Icon::CaretDownFill)
+        } else {
+            (icons::Icon::CaretLeftFill, icons::Icon::CaretRightFill)
+        };
+        let arrows = [
+            (backward, start, offset > 0.0),
+            (
+                forward,
+                viewport_start + viewport_length,
+                offset < self.max_offset(layout, scroll_viewport),
+            ),
+        ];
+        for (icon, arrow_start, is_enabled) in arrows {
+            let arrow_bounds = self.main_slice(bounds, arrow_start, self.arrow_width());
+
+            renderer.fill_text(core::text::Text {
+                content: &icons::icon_to_char(icon).to_string(),
+                bounds: Rectangle {
+                    x: arrow_bounds.center_x(),
+                    y: arrow_bounds.center_y(),
+                    ..arrow_bounds
+                },
+                size: self.close_size,
+                color: if is_enabled {
+                    style_sheet.icon_color
+                } else {
+                    Color {
+                        a: style_sheet.icon_color.a * 0.3,
+                        ..style_sheet.icon_color
+                    }
+                },
+                font: icons::ICON_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: LineHeight::Relative(1.3),
+                shaping: iced_widget::text::Shaping::Basic,
+            });
         }
     }
 }
@@ -608,14 +1598,18 @@ where
     clippy::too_many_lines,
     clippy::too_many_arguments
 )]
-fn draw_tab<Renderer>(
+fn draw_tab<Message, Renderer>(
     renderer: &mut Renderer,
-    tab: &TabLabel,
+    tab: &TabLabel<'_, Message, Renderer>,
+    tree: &Tree,
     layout: Layout<'_>,
+    viewport: &Rectangle,
     position: Position,
+    icons_only: bool,
     theme: &Renderer::Theme,
     style: &<Renderer::Theme as StyleSheet>::Style,
     is_selected: bool,
+    is_disabled: bool,
     cursor: Cursor,
     icon_data: (Font, f32),
     text_data: (Font, f32),
@@ -636,12 +1630,24 @@ fn draw_tab<Renderer>(
     let is_mouse_over = layout
         .bounds()
         .contains(cursor.position().unwrap_or_default());
-    let style = if is_mouse_over {
+    let style = if is_disabled {
+        theme.disabled(style)
+    } else if is_mouse_over {
         theme.hovered(style, is_selected)
     } else {
         theme.active(style, is_selected)
     };
 
+    // The text of an icon and text label is left out if only icons are shown.
+    let icon_label;
+    let tab = match tab {
+        TabLabel::IconText(icon, _) if icons_only => {
+            icon_label = TabLabel::Icon(*icon);
+            &icon_label
+        }
+        _ => tab,
+    };
+
     let bounds = layout.bounds();
     let mut children = layout.children();
     let label_layout = children
@@ -761,6 +1767,19 @@ fn draw_tab<Renderer>(
                 shaping: iced_widget::text::Shaping::Advanced,
             });
         }
+        TabLabel::Element(element) => {
+            element.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                element_layout(layout),
+                cursor,
+                viewport,
+            );
+        }
     };
 
     if let Some(cross_layout) = children.next() {
@@ -799,7 +1818,64 @@ fn draw_tab<Renderer>(
     };
 }
 
-impl<'a, Message, TabId, Renderer> From<TabBar<Message, TabId, Renderer>>
+/// Creates the state of the element of a [`TabLabel::Element`].
+fn label_tree<Message, Renderer>(tab_label: &TabLabel<'_, Message, Renderer>) -> Tree
+where
+    Renderer: core::Renderer,
+{
+    match tab_label {
+        TabLabel::Element(element) => Tree::new(element),
+        _ => Tree::empty(),
+    }
+}
+
+/// Returns the layout of the element of a [`TabLabel::Element`] from the
+/// layout of its tab.
+fn element_layout(tab_layout: Layout<'_>) -> Layout<'_> {
+    tab_layout
+        .children()
+        .next()
+        .and_then(|label_layout| label_layout.children().next())
+        .expect("Native: Layout should have an element layout for an Element")
+}
+
+/// Lays out the element of a [`TabLabel::Element`] along with the other
+/// labels, while the element stays owned by the [`TabBar`].
+struct LabelElement<'b, 'a, Message, Renderer>(&'b Element<'a, Message, Renderer>);
+
+impl<Message, Renderer> Widget<Message, Renderer> for LabelElement<'_, '_, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn width(&self) -> Length {
+        self.0.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.0.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.0.as_widget().layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.0
+            .as_widget()
+            .draw(state, renderer, theme, style, layout, cursor, viewport);
+    }
+}
+
+impl<'a, Message, TabId, Renderer> From<TabBar<'a, Message, TabId, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
@@ -807,7 +1883,7 @@ where
     Message: 'a,
     TabId: 'a + Eq + Clone,
 {
-    fn from(tab_bar: TabBar<Message, TabId, Renderer>) -> Self {
+    fn from(tab_bar: TabBar<'a, Message, TabId, Renderer>) -> Self {
         Element::new(tab_bar)
     }
 }
@@ -1,12 +1,12 @@
 //! A [`TabLabel`] showing an icon and/or a text on a tab.
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
+use iced_widget::core::Element;
 
 /// A [`TabLabel`] showing an icon and/or a text on a tab
 /// on a [`TabBar`](super::TabBar).
 #[allow(missing_debug_implementations)]
-#[derive(Clone, Hash)]
-pub enum TabLabel {
+pub enum TabLabel<'a, Message, Renderer = crate::Renderer> {
     /// A [`TabLabel`] showing only an icon on the tab.
     Icon(char),
 
@@ -15,5 +15,20 @@ pub enum TabLabel {
 
     /// A [`TabLabel`] showing an icon and a text on the tab.
     IconText(char, String),
-    // TODO: Support any element as a label.
+
+    /// A [`TabLabel`] showing any element on the tab, like a spinner, a
+    /// badge with a count or an image.
+    ///
+    /// The [`TabBar`](super::TabBar) still selects the tab when it is
+    /// pressed, draws its background and close icon and passes the text color
+    /// of its style to the element. Events are passed to the element first, so
+    /// that it can animate or capture presses.
+    Element(Element<'a, Message, Renderer>),
+}
+
+impl<'a, Message, Renderer> TabLabel<'a, Message, Renderer> {
+    /// Creates a [`TabLabel`] showing the given element on the tab.
+    pub fn element(element: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self::Element(element.into())
+    }
 }
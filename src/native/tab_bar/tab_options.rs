@@ -0,0 +1,71 @@
+//! The [`TabOptions`] of a single tab on a tab bar.
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+
+/// The [`TabOptions`] of a single tab on a [`TabBar`](super::TabBar), like
+/// browsers and editors offer for their tabs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TabOptions {
+    /// Whether the tab can not be selected.
+    pub(crate) is_disabled: bool,
+    /// Whether the tab is kept at the start.
+    pub(crate) is_pinned: bool,
+    /// Whether the tab has a close icon.
+    pub(crate) is_closable: bool,
+    /// The text shown while hovering the tab.
+    pub(crate) tooltip: Option<String>,
+}
+
+impl Default for TabOptions {
+    fn default() -> Self {
+        Self {
+            is_disabled: false,
+            is_pinned: false,
+            is_closable: true,
+            tooltip: None,
+        }
+    }
+}
+
+impl TabOptions {
+    /// Creates the default [`TabOptions`] of an enabled, unpinned and
+    /// closable tab without tooltip.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the tab is disabled. A disabled tab is drawn in the
+    /// disabled appearance of the style and can neither be selected, closed
+    /// nor dragged.
+    #[must_use]
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the tab is pinned. Pinned tabs are kept at the start of
+    /// the [`TabBar`](super::TabBar), show only the icon of an icon and text
+    /// label, do not fill the width of a horizontal tab bar and have no close
+    /// icon.
+    #[must_use]
+    pub fn pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
+
+    /// Sets whether the tab has a close icon, which it has by default once
+    /// the message of the [`TabBar`](super::TabBar) closing a tab is set.
+    #[must_use]
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+
+    /// Sets the text shown next to the cursor while hovering the tab.
+    #[must_use]
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+}
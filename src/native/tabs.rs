@@ -6,24 +6,81 @@
 //!
 //! *This API requires the following crate features to be activated: tabs*
 
+use std::cell::{Cell, OnceCell};
+
 use iced_widget::{
     core::{
-        self, event,
+        self, event, keyboard,
         layout::{Limits, Node},
         mouse::{self, Cursor},
-        renderer,
-        widget::{Operation, Tree},
+        renderer, touch,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
         Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
     },
     runtime::Font,
     text, Row,
 };
 
-use crate::{native::tab_bar::TabBar, style::tab_bar::StyleSheet, TabLabel};
+use crate::{
+    native::tab_bar::{self, Orientation, Overflow, TabBar, TabOptions},
+    style::tab_bar::StyleSheet,
+    TabLabel,
+};
 
 pub mod tab_bar_position;
 pub use tab_bar_position::TabBarPosition;
 
+/// A tab of a [`Tabs`] widget: its id, its label and its content.
+pub type Tab<'a, Message, TabId, Renderer> = (
+    TabId,
+    TabLabel<'a, Message, Renderer>,
+    Element<'a, Message, Renderer>,
+);
+
+/// The builder of the content of a lazy tab of a [`Tabs`] widget.
+type TabBuilder<'a, Message, Renderer> = Box<dyn FnOnce() -> Element<'a, Message, Renderer> + 'a>;
+
+/// The content of a tab of a [`Tabs`] widget.
+enum TabContent<'a, Message, Renderer> {
+    /// The content built by the caller on every view.
+    Element(Element<'a, Message, Renderer>),
+    /// The content built on demand once the tab is shown.
+    Lazy {
+        /// The builder of the content, taken when it is called.
+        builder: Cell<Option<TabBuilder<'a, Message, Renderer>>>,
+        /// The content once it is built.
+        element: OnceCell<Element<'a, Message, Renderer>>,
+    },
+}
+
+impl<'a, Message, Renderer> TabContent<'a, Message, Renderer> {
+    /// Returns the [`Element`] of the content, building it if needed.
+    fn element(&self) -> &Element<'a, Message, Renderer> {
+        match self {
+            Self::Element(element) => element,
+            Self::Lazy { builder, element } => element.get_or_init(|| {
+                builder
+                    .take()
+                    .expect("Native: lazy tab content should have a builder")()
+            }),
+        }
+    }
+
+    /// Returns the mutable [`Element`] of the content, building it if needed.
+    fn element_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        let _ = self.element();
+        match self {
+            Self::Element(element) => element,
+            Self::Lazy { element, .. } => element
+                .get_mut()
+                .expect("Native: lazy tab content should be built"),
+        }
+    }
+}
+
 /// A [`Tabs`] widget for showing a [`TabBar`](super::tab_bar::TabBar)
 /// along with the tab's content.
 ///
@@ -59,19 +116,32 @@ where
     TabId: Eq + Clone,
 {
     /// The [`TabBar`](crate::native::TabBar) of the [`Tabs`].
-    tab_bar: TabBar<Message, TabId, Renderer>,
+    tab_bar: TabBar<'a, Message, TabId, Renderer>,
     /// The vector containing the content of the tabs.
-    tabs: Vec<Element<'a, Message, Renderer>>,
+    tabs: Vec<TabContent<'a, Message, Renderer>>,
     /// The vector containing the indices of the tabs.
     indices: Vec<TabId>,
     /// The position of the [`TabBar`](crate::native::TabBar).
     tab_bar_position: TabBarPosition,
+    /// Whether the widget state of the inactive tabs is kept.
+    retain_inactive_state: bool,
     /// the width of the [`Tabs`].
     width: Length,
     /// The height of the [`Tabs`].
     height: Length,
 }
 
+/// The state of a [`Tabs`] widget.
+#[derive(Debug)]
+struct TabsState<TabId> {
+    /// Whether the [`Tabs`] were pressed last, so that the tabs are cycled
+    /// with ctrl+tab.
+    is_focused: bool,
+    /// The ids of the tabs whose content states follow the state of the
+    /// [`TabBar`] in the `children` of the tree, in the same order.
+    tab_ids: Vec<TabId>,
+}
+
 impl<'a, Message, TabId, Renderer> Tabs<'a, Message, TabId, Renderer>
 where
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
@@ -101,10 +171,7 @@ where
     ///         [`Element`]s of the [`Tabs`].
     ///     * the function that will be called if a tab is selected by the user.
     ///         It takes the index of the selected tab.
-    pub fn with_tabs<F>(
-        tabs: Vec<(TabId, TabLabel, Element<'a, Message, Renderer>)>,
-        on_select: F,
-    ) -> Self
+    pub fn with_tabs<F>(tabs: Vec<Tab<'a, Message, TabId, Renderer>>, on_select: F) -> Self
     where
         F: 'static + Fn(TabId) -> Message,
     {
@@ -115,7 +182,7 @@ where
         for (id, tab_label, element) in tabs {
             tab_labels.push((id.clone(), tab_label));
             indices.push(id);
-            elements.push(element);
+            elements.push(TabContent::Element(element));
         }
 
         Tabs {
@@ -123,6 +190,7 @@ where
             tabs: elements,
             indices,
             tab_bar_position: TabBarPosition::Top,
+            retain_inactive_state: true,
             width: Length::Fill,
             height: Length::Shrink,
         }
@@ -141,6 +209,29 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab on the [`TabBar`] is
+    /// dragged to another position, with the id of the tab and its new index.
+    #[must_use]
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_reorder(on_reorder);
+        self
+    }
+
+    /// Sets the message that will be produced when a tab is dragged away from
+    /// the [`TabBar`] and dropped, with the id of the tab and the position of
+    /// the cursor.
+    #[must_use]
+    pub fn on_tear_off<F>(mut self, on_tear_off: F) -> Self
+    where
+        F: 'static + Fn(TabId, Point) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_tear_off(on_tear_off);
+        self
+    }
+
     /// Sets the width of the [`Tabs`].
     #[must_use]
     pub fn width(mut self, width: Length) -> Self {
@@ -242,25 +333,179 @@ where
     }
 
     /// Sets the [`TabBarPosition`] of the [`TabBar`](super::tab_bar::TabBar).
+    ///
+    /// The [`TabBar`](super::tab_bar::TabBar) is laid out vertically on the
+    /// left and right, as narrow as its widest tab and as high as the [`Tabs`].
+    /// This resets the width and height of the
+    /// [`TabBar`](super::tab_bar::TabBar) to the defaults of the position, so
+    /// set them afterwards.
     #[must_use]
     pub fn tab_bar_position(mut self, position: TabBarPosition) -> Self {
+        self.tab_bar = match position {
+            TabBarPosition::Top | TabBarPosition::Bottom => self
+                .tab_bar
+                .orientation(Orientation::Horizontal)
+                .width(Length::Fill)
+                .height(Length::Shrink),
+            TabBarPosition::Left | TabBarPosition::Right => self
+                .tab_bar
+                .orientation(Orientation::Vertical)
+                .width(Length::Shrink)
+                .height(Length::Fill),
+        };
         self.tab_bar_position = position;
         self
     }
 
+    /// Sets whether only the icons of the
+    /// [`TabLabel::IconText`](super::tab_bar::TabLabel::IconText) labels are
+    /// shown on the [`TabBar`](super::tab_bar::TabBar), e.g. to keep it narrow
+    /// on the left or right.
+    #[must_use]
+    pub fn icons_only(mut self, icons_only: bool) -> Self {
+        self.tab_bar = self.tab_bar.icons_only(icons_only);
+        self
+    }
+
+    /// Sets how tabs that do not fit into the [`TabBar`](super::tab_bar::TabBar)
+    /// are handled.
+    #[must_use]
+    pub fn tab_bar_overflow(mut self, overflow: Overflow) -> Self {
+        self.tab_bar = self.tab_bar.overflow(overflow);
+        self
+    }
+
     /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) along with the tabs
     /// content to the [`Tabs`].
     #[must_use]
-    pub fn push<E>(mut self, id: TabId, tab_label: TabLabel, element: E) -> Self
+    pub fn push<E>(self, id: TabId, tab_label: TabLabel<'a, Message, Renderer>, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_with_options(id, tab_label, TabOptions::default(), element)
+    }
+
+    /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) with its
+    /// [`TabOptions`] along with the tabs content to the [`Tabs`].
+    ///
+    /// A pinned tab is inserted after the pinned tabs pushed before it.
+    #[must_use]
+    pub fn push_with_options<E>(
+        self,
+        id: TabId,
+        tab_label: TabLabel<'a, Message, Renderer>,
+        options: TabOptions,
+        element: E,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.insert(id, tab_label, options, TabContent::Element(element.into()))
+    }
+
+    /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) along with a builder
+    /// of the tabs content to the [`Tabs`].
+    ///
+    /// The builder is only called if the tab is active, so that the content
+    /// of the other tabs is not built on every view.
+    #[must_use]
+    pub fn push_lazy<F, E>(
+        self,
+        id: TabId,
+        tab_label: TabLabel<'a, Message, Renderer>,
+        builder: F,
+    ) -> Self
+    where
+        F: 'a + FnOnce() -> E,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_lazy_with_options(id, tab_label, TabOptions::default(), builder)
+    }
+
+    /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) with its
+    /// [`TabOptions`] along with a builder of the tabs content to the
+    /// [`Tabs`].
+    ///
+    /// The builder is only called if the tab is active.
+    #[must_use]
+    pub fn push_lazy_with_options<F, E>(
+        self,
+        id: TabId,
+        tab_label: TabLabel<'a, Message, Renderer>,
+        options: TabOptions,
+        builder: F,
+    ) -> Self
     where
+        F: 'a + FnOnce() -> E,
         E: Into<Element<'a, Message, Renderer>>,
     {
-        self.tab_bar = self.tab_bar.push(id.clone(), tab_label);
-        self.tabs.push(element.into());
-        self.indices.push(id);
+        let builder: TabBuilder<'a, Message, Renderer> = Box::new(move || builder().into());
+        self.insert(
+            id,
+            tab_label,
+            options,
+            TabContent::Lazy {
+                builder: Cell::new(Some(builder)),
+                element: OnceCell::new(),
+            },
+        )
+    }
+
+    /// Inserts a tab at the position given by its [`TabOptions`].
+    fn insert(
+        mut self,
+        id: TabId,
+        tab_label: TabLabel<'a, Message, Renderer>,
+        options: TabOptions,
+        content: TabContent<'a, Message, Renderer>,
+    ) -> Self {
+        let index = self.tab_bar.insert(id.clone(), tab_label, options);
+        self.tabs.insert(index, content);
+        self.indices.insert(index, id);
+        self
+    }
+
+    /// Sets whether the widget state of the inactive tabs, like scroll
+    /// positions and the contents of text inputs, is kept until they are
+    /// shown again. It is kept by default.
+    ///
+    /// The state belongs to the ``TabId`` of the tab, so it stays with the tab
+    /// when other tabs are closed, inserted or reordered.
+    #[must_use]
+    pub fn retain_inactive_state(mut self, retain_inactive_state: bool) -> Self {
+        self.retain_inactive_state = retain_inactive_state;
         self
     }
 
+    /// Diffs the states of the tab contents, which follow the state of the
+    /// [`TabBar`] in the `children` of the tree and belong to the given ids.
+    ///
+    /// The states are first moved to the current positions of their tabs.
+    /// The content of the active tab is diffed and built if it is lazy. The
+    /// states of the inactive tabs are kept as they are, diffed if their
+    /// content is already built or dropped if they are not retained.
+    fn diff_tabs(&self, tab_ids: &[TabId], children: &mut Vec<Tree>) {
+        let mut previous: Vec<_> = tab_ids.iter().zip(children.drain(1..)).collect();
+        for id in &self.indices {
+            let state = previous
+                .iter()
+                .position(|(previous_id, _)| *previous_id == id)
+                .map_or_else(Tree::empty, |position| previous.swap_remove(position).1);
+            children.push(state);
+        }
+
+        let active_tab = self.tab_bar.get_active_tab_idx();
+        for (index, (tab, state)) in self.tabs.iter().zip(&mut children[1..]).enumerate() {
+            if index == active_tab {
+                state.diff(tab.element());
+            } else if !self.retain_inactive_state {
+                *state = Tree::empty();
+            } else if let TabContent::Element(element) = tab {
+                state.diff(element);
+            }
+        }
+    }
+
     /// Sets the active tab of the [`Tabs`] using the ``TabId``.
     #[must_use]
     pub fn set_active_tab(mut self, id: &TabId) -> Self {
@@ -273,14 +518,37 @@ impl<'a, Message, TabId, Renderer> Widget<Message, Renderer> for Tabs<'a, Messag
 where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet + text::StyleSheet,
-    TabId: Eq + Clone,
+    TabId: 'static + Eq + Clone,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<TabsState<TabId>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(TabsState {
+            is_focused: false,
+            tab_ids: self.indices.clone(),
+        })
+    }
+
     fn children(&self) -> Vec<Tree> {
-        self.tabs.iter().map(Tree::new).collect()
+        // The state of the tab bar comes first, followed by the content of the tabs.
+        let mut children = vec![Tree::new(&self.tab_bar as &dyn Widget<Message, Renderer>)];
+        self.diff_tabs(&[], &mut children);
+        children
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&self.tabs);
+        let tabs_state = tree.state.downcast_mut::<TabsState<TabId>>();
+        let tab_ids = std::mem::replace(&mut tabs_state.tab_ids, self.indices.clone());
+
+        if tree.children.is_empty() {
+            tree.children = self.children();
+            return;
+        }
+
+        tree.children[0].diff(&self.tab_bar as &dyn Widget<Message, Renderer>);
+        self.diff_tabs(&tab_ids, &mut tree.children);
     }
 
     fn width(&self) -> Length {
@@ -292,11 +560,24 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
-        let tab_bar_limits = limits.width(self.width).height(Length::Shrink);
+        let is_vertical = matches!(
+            self.tab_bar_position,
+            TabBarPosition::Left | TabBarPosition::Right
+        );
+
+        let tab_bar_limits = if is_vertical {
+            limits.width(self.width).height(self.height)
+        } else {
+            limits.width(self.width).height(Length::Shrink)
+        };
 
         let mut tab_bar_node = self.tab_bar.layout(renderer, &tab_bar_limits);
 
-        let tab_content_limits = limits.width(self.width).height(self.height);
+        let mut tab_content_limits = limits.width(self.width).height(self.height);
+        if is_vertical {
+            tab_content_limits =
+                tab_content_limits.shrink(Size::new(tab_bar_node.size().width, 0.0));
+        }
 
         let mut tab_content_node = self
             .tabs
@@ -308,37 +589,39 @@ where
                         .height(Length::Fill)
                         .layout(renderer, &tab_content_limits)
                 },
-                |element| element.as_widget().layout(renderer, &tab_content_limits),
+                |tab| {
+                    tab.element()
+                        .as_widget()
+                        .layout(renderer, &tab_content_limits)
+                },
             );
 
-        tab_bar_node.move_to(Point::new(
-            tab_bar_node.bounds().x,
-            tab_bar_node.bounds().y
-                + match self.tab_bar_position {
-                    TabBarPosition::Top => 0.0,
-                    TabBarPosition::Bottom => tab_content_node.bounds().height,
-                },
-        ));
-
-        tab_content_node.move_to(Point::new(
-            tab_content_node.bounds().x,
-            tab_content_node.bounds().y
-                + match self.tab_bar_position {
-                    TabBarPosition::Top => tab_bar_node.bounds().height,
-                    TabBarPosition::Bottom => 0.0,
-                },
-        ));
+        let tab_bar_size = tab_bar_node.size();
+        let tab_content_size = tab_content_node.size();
 
-        Node::with_children(
+        let (tab_bar_position, tab_content_position) = match self.tab_bar_position {
+            TabBarPosition::Top => (Point::ORIGIN, Point::new(0.0, tab_bar_size.height)),
+            TabBarPosition::Bottom => (Point::new(0.0, tab_content_size.height), Point::ORIGIN),
+            TabBarPosition::Left => (Point::ORIGIN, Point::new(tab_bar_size.width, 0.0)),
+            TabBarPosition::Right => (Point::new(tab_content_size.width, 0.0), Point::ORIGIN),
+        };
+        tab_bar_node.move_to(tab_bar_position);
+        tab_content_node.move_to(tab_content_position);
+
+        let size = if is_vertical {
             Size::new(
-                tab_content_node.size().width,
-                tab_bar_node.size().height + tab_content_node.size().height,
-            ),
-            match self.tab_bar_position {
-                TabBarPosition::Top => vec![tab_bar_node, tab_content_node],
-                TabBarPosition::Bottom => vec![tab_content_node, tab_bar_node],
-            },
-        )
+                tab_bar_size.width + tab_content_size.width,
+                tab_bar_size.height.max(tab_content_size.height),
+            )
+        } else {
+            Size::new(
+                tab_content_size.width,
+                tab_bar_size.height + tab_content_size.height,
+            )
+        };
+
+        // The tab bar is the first child wherever it is placed.
+        Node::with_children(size, vec![tab_bar_node, tab_content_node])
     }
 
     fn on_event(
@@ -352,30 +635,31 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        let mut children = layout.children();
-        let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
-            TabBarPosition::Top => {
-                let tab_bar_layout = children
-                    .next()
-                    .expect("Native: Layout should have a TabBar layout at top position");
-                let tab_content_layout = children
-                    .next()
-                    .expect("Native: Layout should have a tab content layout at top position");
-                (tab_bar_layout, tab_content_layout)
+        let tabs_state = state.state.downcast_mut::<TabsState<TabId>>();
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                tabs_state.is_focused = cursor.is_over(layout.bounds());
             }
-            TabBarPosition::Bottom => {
-                let tab_content_layout = children
-                    .next()
-                    .expect("Native: Layout should have a tab content layout at bottom position");
-                let tab_bar_layout = children
-                    .next()
-                    .expect("Native: Layout should have a TabBar layout at bottom position");
-                (tab_bar_layout, tab_content_layout)
+            // Ctrl+tab cycles through the tabs, forwards or backwards with shift.
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) if modifiers.control()
+                && (tabs_state.is_focused || tab_bar::is_focused(&state.children[0])) =>
+            {
+                if let Some(message) = self.tab_bar.cycle(modifiers.shift()) {
+                    shell.publish(message);
+                }
+                return event::Status::Captured;
             }
-        };
+            _ => {}
+        }
+
+        let (tab_bar_layout, tab_content_layout) = tab_layouts(layout);
 
         let status_tab_bar = self.tab_bar.on_event(
-            &mut Tree::empty(),
+            &mut state.children[0],
             event.clone(),
             tab_bar_layout,
             cursor,
@@ -388,9 +672,9 @@ where
         let status_element = self
             .tabs
             .get_mut(idx)
-            .map_or(event::Status::Ignored, |element| {
-                element.as_widget_mut().on_event(
-                    &mut state.children[idx],
+            .map_or(event::Status::Ignored, |tab| {
+                tab.element_mut().as_widget_mut().on_event(
+                    &mut state.children[idx + 1],
                     event,
                     tab_content_layout,
                     cursor,
@@ -412,20 +696,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        // Tab bar
-        let mut children = layout.children();
-        let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
-                .next()
-                .expect("Native: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
-                .last()
-                .expect("Native: There should be a TabBar at the bottom position"),
-        };
+        let (tab_bar_layout, tab_content_layout) = tab_layouts(layout);
 
+        // Tab bar
         let mut mouse_interaction = mouse::Interaction::default();
         let new_mouse_interaction = self.tab_bar.mouse_interaction(
-            &Tree::empty(),
+            &state.children[0],
             tab_bar_layout,
             cursor,
             viewport,
@@ -437,19 +713,10 @@ where
         }
 
         // Tab content
-        let mut children = layout.children();
-        let tab_content_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
-                .last()
-                .expect("Graphics: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
-                .next()
-                .expect("Graphics: There should be a TabBar at the bottom position"),
-        };
         let idx = self.tab_bar.get_active_tab_idx();
-        if let Some(element) = self.tabs.get(idx) {
-            let new_mouse_interaction = element.as_widget().mouse_interaction(
-                &state.children[idx],
+        if let Some(tab) = self.tabs.get(idx) {
+            let new_mouse_interaction = tab.element().as_widget().mouse_interaction(
+                &state.children[idx + 1],
                 tab_content_layout,
                 cursor,
                 viewport,
@@ -474,18 +741,10 @@ where
         cursor: Cursor,
         viewport: &Rectangle,
     ) {
-        let mut children = layout.children();
-        let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
-                .next()
-                .expect("Native: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
-                .last()
-                .expect("Native: There should be a TabBar at the bottom position"),
-        };
+        let (tab_bar_layout, tab_content_layout) = tab_layouts(layout);
 
         self.tab_bar.draw(
-            &Tree::empty(),
+            &state.children[0],
             renderer,
             theme,
             style,
@@ -494,21 +753,10 @@ where
             viewport,
         );
 
-        let mut children = layout.children();
-
-        let tab_content_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
-                .last()
-                .expect("Graphics: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
-                .next()
-                .expect("Graphics: There should be a TabBar at the bottom position"),
-        };
-
         let idx = self.tab_bar.get_active_tab_idx();
-        if let Some(element) = self.tabs.get(idx) {
-            element.as_widget().draw(
-                &state.children[idx],
+        if let Some(tab) = self.tabs.get(idx) {
+            tab.element().as_widget().draw(
+                &state.children[idx + 1],
                 renderer,
                 theme,
                 style,
@@ -525,18 +773,26 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<core::overlay::Element<'b, Message, Renderer>> {
-        let layout = match self.tab_bar_position {
-            TabBarPosition::Top => layout.children().nth(1),
-            TabBarPosition::Bottom => layout.children().next(),
-        };
+        let (tab_bar_layout, tab_content_layout) = tab_layouts(layout);
+        let (tab_bar_state, tab_content_states) = state.children.split_first_mut()?;
 
-        layout.and_then(|layout| {
-            let idx = self.tab_bar.get_active_tab_idx();
-            self.tabs
-                .get_mut(idx)
-                .map(Element::as_widget_mut)
-                .and_then(|w| w.overlay(&mut state.children[idx], layout, renderer))
-        })
+        let idx = self.tab_bar.get_active_tab_idx();
+        let tab_bar = self
+            .tab_bar
+            .overlay(tab_bar_state, tab_bar_layout, renderer);
+
+        let tab_content = self
+            .tabs
+            .get_mut(idx)
+            .map(|tab| tab.element_mut().as_widget_mut())
+            .and_then(|w| w.overlay(&mut tab_content_states[idx], tab_content_layout, renderer));
+
+        match (tab_bar, tab_content) {
+            (Some(tab_bar), Some(tab_content)) => {
+                Some(core::overlay::Group::with_children(vec![tab_bar, tab_content]).overlay())
+            }
+            (tab_bar, tab_content) => tab_bar.or(tab_content),
+        }
     }
 
     fn operate(
@@ -546,30 +802,101 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        let (tab_bar_layout, tab_content_layout) = tab_layouts(layout);
         let active_tab = self.tab_bar.get_active_tab_idx();
         operation.container(None, layout.bounds(), &mut |operation| {
-            self.tabs[active_tab].as_widget().operate(
-                &mut tree.children[active_tab],
-                layout
-                    .children()
-                    .nth(1)
-                    .expect("TabBar is 0th child, contents are 1st node"),
-                renderer,
-                operation,
-            );
+            self.tab_bar
+                .operate(&mut tree.children[0], tab_bar_layout, renderer, operation);
+
+            if let Some(tab) = self.tabs.get(active_tab) {
+                tab.element().as_widget().operate(
+                    &mut tree.children[active_tab + 1],
+                    tab_content_layout,
+                    renderer,
+                    operation,
+                );
+            }
         });
     }
 }
 
+/// Returns the layouts of the [`TabBar`] and of the content of the active tab.
+fn tab_layouts(layout: Layout<'_>) -> (Layout<'_>, Layout<'_>) {
+    let mut children = layout.children();
+    let tab_bar_layout = children
+        .next()
+        .expect("Native: Layout should have a TabBar layout");
+    let tab_content_layout = children
+        .next()
+        .expect("Native: Layout should have a tab content layout");
+
+    (tab_bar_layout, tab_content_layout)
+}
+
 impl<'a, Message, TabId, Renderer> From<Tabs<'a, Message, TabId, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet + text::StyleSheet,
     Message: 'a,
-    TabId: 'a + Eq + Clone,
+    TabId: 'static + Eq + Clone,
 {
     fn from(tabs: Tabs<'a, Message, TabId, Renderer>) -> Self {
         Element::new(tabs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Tabs;
+    use crate::TabLabel;
+    use iced_widget::{
+        core::{widget::Tree, Widget},
+        text_input, TextInput,
+    };
+
+    /// Builds [`Tabs`] with a text input on each of the given tabs.
+    fn tabs(ids: &[&'static str], active: &'static str) -> Tabs<'static, (), &'static str> {
+        ids.iter()
+            .fold(Tabs::new(|_| ()), |tabs, &id| {
+                tabs.push(
+                    id,
+                    TabLabel::Text(id.to_owned()),
+                    TextInput::new(id, "").on_input(|_| ()),
+                )
+            })
+            .set_active_tab(&active)
+    }
+
+    fn is_focused(tree: &Tree, index: usize) -> bool {
+        tree.children[index + 1]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused()
+    }
+
+    #[test]
+    fn state_follows_tab_id_test() {
+        let before = tabs(&["one", "two", "three"], "three");
+        let mut tree = Tree::new(&before as &dyn Widget<_, _>);
+        tree.children[3]
+            .state
+            .downcast_mut::<text_input::State>()
+            .focus();
+
+        // Closing a tab before the active one keeps the state with its tab.
+        let after = tabs(&["two", "three"], "three");
+        tree.diff(&after as &dyn Widget<_, _>);
+        assert_eq!(tree.children.len(), 3);
+        assert!(!is_focused(&tree, 0));
+        assert!(is_focused(&tree, 1));
+
+        // So does switching to another tab and back.
+        let switched = tabs(&["two", "three"], "two");
+        tree.diff(&switched as &dyn Widget<_, _>);
+        let back = tabs(&["three", "two"], "three");
+        tree.diff(&back as &dyn Widget<_, _>);
+        assert!(is_focused(&tree, 0));
+        assert!(!is_focused(&tree, 1));
+    }
+}
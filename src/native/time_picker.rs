@@ -1,8 +1,6 @@
 //! Use a time picker as an input element for picking times.
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
-use chrono::Local;
-
 use iced_widget::{
     button, container,
     core::{
@@ -19,7 +17,7 @@ use iced_widget::{
 
 use super::overlay::time_picker::{self, TimePickerOverlay, TimePickerOverlayButtons};
 
-pub use crate::core::time::{Period, Time};
+pub use crate::core::time::{ParseTimeError, Period, Time};
 
 pub use crate::style::time_picker::{Appearance, StyleSheet};
 
@@ -161,7 +159,7 @@ impl State {
     /// Resets the time of the state to the current time.
     pub fn reset(&mut self) {
         self.overlay_state.clock_cache.clear();
-        self.overlay_state.time = Local::now().naive_local().time();
+        self.overlay_state.time = Time::now_hms(true);
         self.overlay_state.use_24h = false;
         self.overlay_state.show_seconds = false;
     }
@@ -0,0 +1,87 @@
+//! Use a clock to display the current time.
+//!
+//! *This API requires the following crate features to be activated: `clock`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Clock`](crate::native::Clock).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the face of the [`Clock`](crate::native::Clock).
+    pub background: Background,
+
+    /// The border width of the face of the [`Clock`](crate::native::Clock).
+    pub border_width: f32,
+
+    /// The border color of the face of the [`Clock`](crate::native::Clock).
+    pub border_color: Color,
+
+    /// The color of the tick marks of the [`Clock`](crate::native::Clock).
+    pub tick_color: Color,
+
+    /// The color of the hour and minute hands of the
+    /// [`Clock`](crate::native::Clock).
+    pub hand_color: Color,
+
+    /// The width of the hour and minute hands of the
+    /// [`Clock`](crate::native::Clock).
+    pub hand_width: f32,
+
+    /// The color of the second hand of the [`Clock`](crate::native::Clock).
+    pub second_hand_color: Color,
+
+    /// The width of the second hand of the [`Clock`](crate::native::Clock).
+    pub second_hand_width: f32,
+}
+
+/// The appearance of a [`Clock`](crate::native::Clock).
+pub trait StyleSheet {
+    /// The style type of this stylesheet
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Clock`](crate::native::Clock).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The style appearance of the [`Clock`](crate::native::Clock)
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ClockStyle {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ClockStyle {
+    /// Creates a custom [`ClockStyle`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ClockStyle;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ClockStyle::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+        let foreground = self.palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_width: 1.0,
+            border_color: foreground.text,
+            tick_color: palette.background.strong.color,
+            hand_color: foreground.text,
+            hand_width: 3.0,
+            second_hand_color: palette.primary.strong.color,
+            second_hand_width: 1.0,
+        }
+    }
+}
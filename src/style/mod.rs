@@ -13,6 +13,11 @@ pub mod card;
 #[cfg(feature = "card")]
 pub use card::CardStyles;
 
+#[cfg(feature = "clock")]
+pub mod clock;
+#[cfg(feature = "clock")]
+pub use clock::ClockStyle;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 
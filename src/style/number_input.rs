@@ -14,6 +14,10 @@ pub struct Appearance {
     pub button_background: Option<Background>,
     /// The Color of the arrows of [`NumberInput`](crate::native::number_input::NumberInput).
     pub icon_color: Color,
+    /// The color of the border drawn around the field of the [`NumberInput`](crate::native::number_input::NumberInput), if any.
+    pub border_color: Option<Color>,
+    /// The color of the error message of the [`NumberInput`](crate::native::number_input::NumberInput).
+    pub message_color: Color,
 }
 
 impl Default for Appearance {
@@ -21,6 +25,8 @@ impl Default for Appearance {
         Self {
             button_background: None,
             icon_color: Color::BLACK,
+            border_color: None,
+            message_color: Color::BLACK,
         }
     }
 }
@@ -37,6 +43,13 @@ pub trait StyleSheet {
 
     /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is disabled.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the text of the [`NumberInput`](crate::native::number_input::NumberInput) is invalid.
+    ///
+    /// Defaults to the active appearance.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 #[derive(Default)]
@@ -68,6 +81,8 @@ impl StyleSheet for Theme {
         Appearance {
             button_background: Some(palette.primary.strong.color.into()),
             icon_color: palette.primary.strong.text,
+            border_color: None,
+            message_color: palette.background.base.text,
         }
     }
 
@@ -98,6 +113,23 @@ impl StyleSheet for Theme {
                 a: active.icon_color.a * 0.5,
                 ..active.icon_color
             },
+            border_color: active.border_color,
+            message_color: active.message_color,
+        }
+    }
+
+    /// The appearance when the text of the [`NumberInput`](crate::native::number_input::NumberInput) is invalid.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        if let NumberInputStyles::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: Some(palette.danger.base.color),
+            message_color: palette.danger.base.color,
+            ..self.active(style)
         }
     }
 }
@@ -28,6 +28,14 @@ pub struct Appearance {
     pub selected_text_color: Color,
     /// The List Label Text Select Background Color
     pub selected_background: Background,
+    /// The background of the characters of a label matching the search query
+    pub matched_background: Background,
+    /// The text color of the section headers
+    pub header_text_color: Color,
+    /// The background of the section headers
+    pub header_background: Background,
+    /// The color of the line showing where a dragged option is dropped
+    pub drop_indicator_color: Color,
 }
 
 impl std::default::Default for Appearance {
@@ -41,6 +49,10 @@ impl std::default::Default for Appearance {
             hovered_background: Background::Color([0.0, 0.5, 1.0].into()),
             selected_text_color: Color::WHITE,
             selected_background: Background::Color([0.2, 0.5, 0.8].into()),
+            matched_background: Background::Color([1.0, 0.85, 0.0, 0.5].into()),
+            header_text_color: [0.3, 0.3, 0.3].into(),
+            header_background: Background::Color([0.8, 0.8, 0.8].into()),
+            drop_indicator_color: [0.0, 0.5, 1.0].into(),
         }
     }
 }
@@ -87,6 +99,14 @@ impl StyleSheet for Theme {
             hovered_background: palette.primary.weak.color.into(),
             selected_text_color: palette.primary.strong.text,
             selected_background: palette.primary.strong.color.into(),
+            matched_background: Color {
+                a: 0.4,
+                ..palette.primary.base.color
+            }
+            .into(),
+            header_text_color: palette.background.strong.text,
+            header_background: palette.background.strong.color.into(),
+            drop_indicator_color: palette.primary.base.color,
             ..Appearance::default()
         }
     }
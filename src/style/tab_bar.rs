@@ -61,6 +61,13 @@ pub trait StyleSheet {
     ///
     /// `is_active` is true if the tab is selected.
     fn hovered(&self, style: &Self::Style, is_active: bool) -> Appearance;
+
+    /// The appearance of a disabled tab label.
+    ///
+    /// Defaults to the inactive appearance faded to half its alpha.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        faded(&self.active(style, false))
+    }
 }
 
 impl Default for Appearance {
@@ -226,4 +233,30 @@ impl StyleSheet for Theme {
             TabBarStyles::Custom(custom) => custom.hovered(self, is_active),
         }
     }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let TabBarStyles::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        faded(&self.active(style, false))
+    }
+}
+
+/// Fades the colors of an [`Appearance`] to half their alpha.
+fn faded(appearance: &Appearance) -> Appearance {
+    let fade = |color: Color| Color {
+        a: color.a * 0.5,
+        ..color
+    };
+
+    Appearance {
+        tab_label_background: match appearance.tab_label_background {
+            Background::Color(color) => Background::Color(fade(color)),
+            Background::Gradient(gradient) => Background::Gradient(gradient),
+        },
+        icon_color: fade(appearance.icon_color),
+        text_color: fade(appearance.text_color),
+        ..*appearance
+    }
 }